@@ -1,10 +1,10 @@
 // ABV calculator from original and final gravity.
 
 use mazerion_core::{
-    register_calculator, CalcInput, CalcResult, Calculator, Error, Measurement, Result, Unit,
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement,
+    Quantity, Result, Sg, Unit,
 };
 use rust_decimal::Decimal;
-use std::str::FromStr;
 
 /// Calculate alcohol by volume from gravity readings.
 #[derive(Default)]
@@ -27,28 +27,33 @@ impl Calculator for AbvCalculator {
         "Calculate alcohol by volume from original and final specific gravity"
     }
 
+    fn category(&self) -> Category {
+        Category::Basic
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["alcohol", "gravity"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["hydrometer", "og", "fg"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        10
+    }
+
     fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
         self.validate(&input)?;
 
-        let og = input
-            .get_param("og")
-            .ok_or_else(|| Error::MissingInput("og parameter required".into()))?;
-        let fg = input
-            .get_param("fg")
-            .ok_or_else(|| Error::MissingInput("fg parameter required".into()))?;
-
-        let og_val: Decimal = og
-            .parse()
-            .map_err(|_| Error::Parse(format!("Invalid OG: {}", og)))?;
-        let fg_val: Decimal = fg
-            .parse()
-            .map_err(|_| Error::Parse(format!("Invalid FG: {}", fg)))?;
-
-        if og_val < fg_val {
+        let og = input.get_quantity::<Sg>("og")?.base();
+        let fg = input.get_quantity::<Sg>("fg")?.base();
+
+        if og < fg {
             return Err(Error::Validation("OG must be >= FG".into()));
         }
 
-        let abv = (og_val - fg_val) * Decimal::new(13125, 2); // 131.25
+        let abv = (og - fg) * Decimal::new(13125, 2); // 131.25
 
         let mut result = CalcResult::new(Measurement::new(abv, Unit::Abv));
 
@@ -57,9 +62,10 @@ impl Calculator for AbvCalculator {
         }
 
         result = result
-            .with_meta("og", og)
-            .with_meta("fg", fg)
-            .with_meta("formula", "Standard ABV = (OG - FG) × 131.25");
+            .with_meta("og", og.to_string())
+            .with_meta("fg", fg.to_string())
+            .with_meta("formula", "Standard ABV = (OG - FG) × 131.25")
+            .with_step("ABV", "(OG − FG) × 131.25", format!("({} − {}) × 131.25", og, fg), abv);
 
         Ok(result)
     }
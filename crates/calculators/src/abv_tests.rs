@@ -1 +1,64 @@
-// abv tests - not implemented yet
+use super::*;
+use mazerion_core::{CalculationRecord, GoalSeek, Sensitivity, Sweep, SweepRange};
+
+#[test]
+fn test_abv_from_gravities() -> Result<()> {
+    let input = CalcInput::new().add_param("og", "1.100").add_param("fg", "1.000");
+    let result = AbvCalculator.calculate(input)?;
+    assert_eq!(result.output.value, Decimal::new(13125, 3));
+    assert_eq!(result.trace.len(), 1);
+    assert_eq!(result.trace[0].substituted, "(1.100 − 1.000) × 131.25");
+    assert!(result.explain().contains("= 13.125\nResult: 13.125"));
+    Ok(())
+}
+
+#[test]
+fn test_gravities_are_range_checked() {
+    let input = CalcInput::new().add_param("og", "10.5").add_param("fg", "1.000");
+    assert!(matches!(AbvCalculator.calculate(input), Err(Error::OutOfRange(_))));
+}
+
+#[test]
+fn test_goal_seek_og_for_target_abv() -> Result<()> {
+    // What OG gives 14% with FG 1.010?
+    let input = CalcInput::new().add_param("fg", "1.010");
+    let bounds = (Decimal::new(1010, 3), Decimal::new(1200, 3));
+    let solution = GoalSeek::new(&AbvCalculator, input, "og", bounds, Decimal::from(14)).solve()?;
+    assert!(solution.converged);
+    assert_eq!(solution.value.round_dp(4), Decimal::new(11167, 4));
+    Ok(())
+}
+
+#[test]
+fn test_sweep_fg_range() -> Result<()> {
+    let fg = SweepRange::new("fg", Decimal::new(990, 3), Decimal::new(1030, 3), Decimal::new(2, 3));
+    let input = CalcInput::new().add_param("og", "1.100");
+    let grid = Sweep::new(&AbvCalculator, input, fg).run()?;
+    assert_eq!(grid.points.len(), 21);
+    let first = grid.points[0].outcome.as_ref().map(|r| r.output.value);
+    assert_eq!(first.ok(), Some(Decimal::new(1443750, 5)));
+    assert!(grid.warnings().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_sensitivity_to_gravities() -> Result<()> {
+    let input = CalcInput::new().add_param("og", "1.100").add_param("fg", "1.000");
+    let report = Sensitivity::new(&AbvCalculator, input).report()?;
+    let derivatives: Vec<_> = report.entries.iter().map(|e| e.derivative).collect();
+    assert_eq!(derivatives, vec![Decimal::new(13125, 2), Decimal::new(-13125, 2)]);
+    Ok(())
+}
+
+#[test]
+fn test_record_round_trip_reproduces() -> Result<()> {
+    let input = CalcInput::new().add_param("og", "1.100").add_param("fg", "1.010");
+    let record = CalculationRecord::calculate(&AbvCalculator, input)?;
+    let text = toml::to_string(&record).map_err(|e| Error::Parse(e.to_string()))?;
+    let stored: CalculationRecord = toml::from_str(&text).map_err(|e| Error::Parse(e.to_string()))?;
+    assert!(stored.verify());
+    assert_eq!(stored.hash, record.hash);
+    let replay = stored.replay()?;
+    assert!(replay.reproduced() && !replay.version_changed());
+    Ok(())
+}
@@ -0,0 +1,148 @@
+// Alcohol unit conversions: ABV, ABW, proof and standard drinks.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement,
+    Quantity, Result, Sg, Unit, Validator,
+};
+use rust_decimal::Decimal;
+
+/// National standard drink definitions: (key, label, grams of ethanol ×100).
+const STANDARD_DRINKS: [(&str, &str, i64); 8] = [
+    ("us", "US", 1400),
+    ("uk", "UK", 800),
+    ("australia", "Australia", 1000),
+    ("canada", "Canada", 1345),
+    ("new_zealand", "New Zealand", 1000),
+    ("ireland", "Ireland", 1000),
+    ("japan", "Japan", 2000),
+    ("who", "WHO", 1000),
+];
+
+/// Ethanol density in g/mL at 20 °C.
+const ETHANOL_DENSITY: Decimal = Decimal::from_parts(789, 0, 0, false, 3);
+
+/// Convert between ABV, ABW, US/UK proof and standard drinks per container.
+#[derive(Default)]
+pub struct AlcoholUnitsCalculator;
+
+impl AlcoholUnitsCalculator {
+    pub const ID: &'static str = "alcohol_units";
+}
+
+impl Calculator for AlcoholUnitsCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Alcohol Units"
+    }
+
+    fn description(&self) -> &'static str {
+        "Convert between ABV, ABW, US/UK proof and national standard drinks"
+    }
+
+    fn category(&self) -> Category {
+        Category::Basic
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["alcohol", "conversion"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["proof", "abw", "standard drinks"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        30
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let sg = input.get_quantity_or::<Sg>("sg", Decimal::ONE)?.base();
+
+        let (abv, source) = if input.get_param("abv").is_some() {
+            (input.get_decimal("abv")?, "abv")
+        } else if input.get_param("abw").is_some() {
+            (input.get_decimal("abw")? * sg / ETHANOL_DENSITY, "abw")
+        } else if input.get_param("us_proof").is_some() {
+            (input.get_decimal("us_proof")? / Decimal::TWO, "us_proof")
+        } else {
+            (
+                input.get_decimal("uk_proof")? * Decimal::from(4) / Decimal::from(7),
+                "uk_proof",
+            )
+        };
+        Validator::percent(abv)?;
+
+        let abw = abv * ETHANOL_DENSITY / sg;
+        let uk_proof = abv * Decimal::from(7) / Decimal::from(4);
+        let mut result = CalcResult::new(Measurement::new(abv, Unit::Abv))
+            .with_secondary("ABW", abw.round_dp(2), Unit::Percent)
+            .with_secondary("US proof", (abv * Decimal::TWO).round_dp(1), Unit::Proof)
+            .with_secondary("UK proof", uk_proof.round_dp(1), Unit::Proof)
+            .with_step("ABW", "ABV × 0.789 / SG", format!("{} × 0.789 / {}", abv, sg), abw);
+
+        if input.get_param("package_volume").is_some() {
+            let package_ml = input.get_decimal("package_volume")?;
+            if package_ml <= Decimal::ZERO {
+                return Err(Error::Validation("Package volume must be positive".into()));
+            }
+            let country = input.get_param("country").unwrap_or("us");
+            if !STANDARD_DRINKS.iter().any(|(key, _, _)| *key == country) {
+                return Err(Error::Validation(format!(
+                    "Unknown standard drink: {}",
+                    country
+                )));
+            }
+
+            let ethanol_g = package_ml * abv / Decimal::ONE_HUNDRED * ETHANOL_DENSITY;
+            let working = format!("{} × {} / 100 × 0.789", package_ml, abv);
+            result = result
+                .with_meta("ethanol_grams", format!("{} g", ethanol_g.round_dp(1)))
+                .with_step("Ethanol", "mL × ABV / 100 × 0.789", working, ethanol_g);
+            // Selected country first, then the rest of the table for comparison.
+            let ordered = STANDARD_DRINKS
+                .iter()
+                .filter(|(key, _, _)| *key == country)
+                .chain(STANDARD_DRINKS.iter().filter(|(key, _, _)| *key != country));
+            for (_, label, grams) in ordered {
+                let drinks = ethanol_g / Decimal::new(*grams, 2);
+                let label = format!(
+                    "Standard drinks ({}, {} g)",
+                    label,
+                    Decimal::new(*grams, 2).normalize()
+                );
+                result = result.with_secondary(label, drinks.round_dp(1), Unit::StandardDrinks);
+            }
+        }
+
+        Ok(result
+            .with_meta("source", source)
+            .with_meta("sg", sg.to_string())
+            .with_meta(
+                "formula",
+                "ABW = ABV × 0.789 / SG; US proof = 2 × ABV; UK proof = 1.75 × ABV",
+            ))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        if !["abv", "abw", "us_proof", "uk_proof"]
+            .iter()
+            .any(|key| input.get_param(key).is_some())
+        {
+            return Err(Error::MissingInput(
+                "abv, abw, us_proof or uk_proof required".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(AlcoholUnitsCalculator);
+
+#[cfg(test)]
+#[path = "alcohol_units_tests.rs"]
+mod tests;
@@ -0,0 +1,72 @@
+use super::*;
+use crate::test_support::secondary;
+
+#[test]
+fn test_abv_to_proof_and_abw() -> Result<()> {
+    let input = CalcInput::new().add_param("abv", "40");
+    let result = AlcoholUnitsCalculator.calculate(input)?;
+    assert_eq!(result.output.value, Decimal::from(40));
+    assert_eq!(secondary(&result, "US proof"), Some(Decimal::from(80)));
+    assert_eq!(secondary(&result, "UK proof"), Some(Decimal::from(70)));
+    assert_eq!(secondary(&result, "ABW"), Some(Decimal::new(3156, 2)));
+    Ok(())
+}
+
+#[test]
+fn test_reverse_conversions() -> Result<()> {
+    let abw = CalcInput::new().add_param("abw", "7.89");
+    assert_eq!(
+        AlcoholUnitsCalculator.calculate(abw)?.output.value,
+        Decimal::from(10)
+    );
+    let us = CalcInput::new().add_param("us_proof", "100");
+    assert_eq!(
+        AlcoholUnitsCalculator.calculate(us)?.output.value,
+        Decimal::from(50)
+    );
+    let uk = CalcInput::new().add_param("uk_proof", "70");
+    assert_eq!(
+        AlcoholUnitsCalculator.calculate(uk)?.output.value,
+        Decimal::from(40)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_standard_drinks_per_bottle() -> Result<()> {
+    let input = CalcInput::new()
+        .add_param("abv", "13.5")
+        .add_param("package_volume", "750")
+        .add_param("country", "uk");
+    let result = AlcoholUnitsCalculator.calculate(input)?;
+    assert_eq!(result.secondary[3].label, "Standard drinks (UK, 8 g)");
+    assert_eq!(result.secondary[3].value, Decimal::from(10));
+    assert_eq!(
+        secondary(&result, "Standard drinks (US, 14 g)"),
+        Some(Decimal::new(57, 1))
+    );
+    assert_eq!(result.secondary.len(), 3 + STANDARD_DRINKS.len());
+    assert_eq!(result.trace[1].substituted, "750 × 13.5 / 100 × 0.789");
+    Ok(())
+}
+
+#[test]
+fn test_unknown_country() {
+    let input = CalcInput::new()
+        .add_param("abv", "5")
+        .add_param("package_volume", "355")
+        .add_param("country", "mars");
+    assert!(matches!(
+        AlcoholUnitsCalculator.calculate(input),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_missing_strength() {
+    let input = CalcInput::new().add_param("package_volume", "355");
+    assert!(matches!(
+        AlcoholUnitsCalculator.calculate(input),
+        Err(Error::MissingInput(_))
+    ));
+}
@@ -0,0 +1,138 @@
+// Bottle pressure safety check for primed or sweetened bottles.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Result,
+    Temperature, Unit, Volume,
+};
+use rust_decimal::Decimal;
+
+use crate::co2::{
+    find_bottle, pressure_psi, psi_to_bar, residual_volumes, BOTTLES, PRESSURE_FORMULA,
+};
+
+/// Estimate final bottle pressure and compare it with bottle limits.
+#[derive(Default)]
+pub struct BottlePressureCalculator;
+
+impl BottlePressureCalculator {
+    pub const ID: &'static str = "bottle_pressure";
+}
+
+impl Calculator for BottlePressureCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Bottle Pressure Safety"
+    }
+
+    fn description(&self) -> &'static str {
+        "Estimate bottle pressure from residual CO₂, priming and fermentable sugar"
+    }
+
+    fn category(&self) -> Category {
+        Category::Brewing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["carbonation", "safety"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["bottle bombs", "priming", "psi"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        70
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let storage_temp: Temperature = input.get_quantity_or("storage_temp", Decimal::from(20))?;
+
+        let residual_co2 = if input.get_param("residual_co2").is_some() {
+            input.get_decimal("residual_co2")?
+        } else {
+            residual_volumes(input.get_quantity_or("fermentation_temp", Decimal::from(20))?)
+        };
+
+        let priming_g_l = if input.get_param("priming_sugar").is_some() {
+            let volume: Volume = input.get_quantity("volume")?;
+            input.get_decimal("priming_sugar")? / volume.as_liters()
+        } else {
+            Decimal::ZERO
+        };
+        let residual_sugar = input.get_decimal_or("residual_sugar", Decimal::ZERO)?;
+        if [residual_co2, priming_g_l, residual_sugar].iter().any(|v| *v < Decimal::ZERO) {
+            return Err(Error::Validation("CO₂ and sugar amounts cannot be negative".into()));
+        }
+
+        // Sucrose yields 0.51 g CO₂/g, hexoses 0.49 g/g; 1 volume = 1.977 g/L CO₂.
+        let co2_grams = priming_g_l * Decimal::new(51, 2) + residual_sugar * Decimal::new(49, 2);
+        let volumes = residual_co2 + co2_grams / Decimal::new(1977, 3);
+        let psi = pressure_psi(storage_temp, volumes).max(Decimal::ZERO);
+        let sugar_working =
+            format!("{} × 0.51 + {} × 0.49", priming_g_l.round_dp(2), residual_sugar);
+        let volumes_working = format!("{} + {} / 1.977", residual_co2, co2_grams.round_dp(3));
+        let pressure_working =
+            format!("T = {}, V = {}", storage_temp.as_fahrenheit(), volumes.round_dp(3));
+
+        let bottle = find_bottle(input.get_param("bottle").unwrap_or("standard"))?;
+        let limit = Decimal::from(bottle.max_psi);
+
+        let mut result = CalcResult::new(Measurement::new(psi, Unit::Psi));
+        let level = if psi > limit {
+            result = result.with_warning(format!(
+                "DANGER: {} psi exceeds the {} psi limit of a {} — bottle bomb risk",
+                psi.round_dp(1),
+                limit,
+                bottle.name.to_lowercase()
+            ));
+            "danger"
+        } else if psi > limit * Decimal::new(8, 1) {
+            result = result.with_warning("Pressure within 20% of the bottle limit");
+            "caution"
+        } else {
+            "safe"
+        };
+        if !residual_sugar.is_zero() {
+            result = result.with_note("Fermentable residual sugar assumed to ferment fully in bottle");
+        }
+
+        for other in &BOTTLES {
+            let verdict = if psi > Decimal::from(other.max_psi) { "exceeds" } else { "ok" };
+            result = result.with_meta(other.key, format!("{} (max {} psi)", verdict, other.max_psi));
+        }
+
+        Ok(result
+            .with_meta("danger_level", level)
+            .with_meta("pressure_bar", psi_to_bar(psi).round_dp(2).to_string())
+            .with_meta("co2_volumes", volumes.round_dp(2).to_string())
+            .with_meta("storage_temp", format!("{} °C", storage_temp.as_celsius()))
+            .with_meta("bottle", bottle.name)
+            .with_step("Sugar CO₂", "priming × 0.51 + residual × 0.49", sugar_working, co2_grams)
+            .with_step("CO₂ volumes", "residual CO₂ + CO₂ g/L / 1.977", volumes_working, volumes)
+            .with_step("Pressure", PRESSURE_FORMULA, pressure_working, psi))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        let has = |key: &str| input.get_param(key).is_some();
+        if !has("priming_sugar") && !has("residual_sugar") && !has("residual_co2") {
+            return Err(Error::MissingInput(
+                "priming_sugar, residual_sugar or residual_co2 required".into(),
+            ));
+        }
+        if has("priming_sugar") && !has("volume") {
+            return Err(Error::MissingInput("volume required with priming_sugar".into()));
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(BottlePressureCalculator);
+
+#[cfg(test)]
+#[path = "bottle_pressure_tests.rs"]
+mod tests;
@@ -0,0 +1,71 @@
+use super::*;
+use crate::test_support::meta;
+
+#[test]
+fn test_normal_priming_is_safe() -> Result<()> {
+    // 6 g/L sucrose on top of 0.86 volumes residual from a 20 °C ferment.
+    let input = CalcInput::new()
+        .add_param("priming_sugar", "120")
+        .add_param("volume", "20");
+    let result = BottlePressureCalculator.calculate(input)?;
+    assert_eq!(result.output.unit, Unit::Psi);
+    assert_eq!(result.output.value.round_dp(1), Decimal::new(262, 1));
+    assert_eq!(meta(&result, "danger_level"), Some("safe"));
+    assert_eq!(meta(&result, "co2_volumes"), Some("2.41"));
+    assert_eq!(result.trace[0].substituted, "6 × 0.51 + 0 × 0.49");
+    assert!(result.warnings.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_unstabilized_sweet_mead_is_dangerous() -> Result<()> {
+    let input = CalcInput::new()
+        .add_param("residual_sugar", "20")
+        .add_param("storage_temp", "25");
+    let result = BottlePressureCalculator.calculate(input)?;
+    assert_eq!(meta(&result, "danger_level"), Some("danger"));
+    assert!(result.warnings[0].starts_with("DANGER"));
+    assert_eq!(meta(&result, "champagne"), Some("ok (max 90 psi)"));
+    assert_eq!(result.notes.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_champagne_bottle_holds_more() -> Result<()> {
+    let input = CalcInput::new()
+        .add_param("residual_co2", "4.5")
+        .add_param("bottle", "champagne");
+    let result = BottlePressureCalculator.calculate(input)?;
+    assert_eq!(meta(&result, "danger_level"), Some("safe"));
+    assert_eq!(meta(&result, "standard"), Some("exceeds (max 40 psi)"));
+    Ok(())
+}
+
+#[test]
+fn test_caution_near_limit() -> Result<()> {
+    let input = CalcInput::new().add_param("residual_co2", "3.0");
+    let result = BottlePressureCalculator.calculate(input)?;
+    assert_eq!(meta(&result, "danger_level"), Some("caution"));
+    Ok(())
+}
+
+#[test]
+fn test_unknown_bottle() {
+    let input = CalcInput::new()
+        .add_param("residual_co2", "2.5")
+        .add_param("bottle", "jam_jar");
+    assert!(matches!(
+        BottlePressureCalculator.calculate(input),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_missing_inputs() {
+    assert!(BottlePressureCalculator.calculate(CalcInput::new()).is_err());
+    let no_volume = CalcInput::new().add_param("priming_sugar", "120");
+    assert!(matches!(
+        BottlePressureCalculator.calculate(no_volume),
+        Err(Error::MissingInput(_))
+    ));
+}
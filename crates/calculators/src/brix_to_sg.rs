@@ -1,11 +1,8 @@
 // Convert degrees Brix to specific gravity.
 
 use mazerion_core::{
-    register_calculator, CalcInput, CalcResult, Calculator, Measurement, Result, Unit,
-    Validator,
+    register_calculator, Brix, CalcInput, CalcResult, Calculator, Category, Quantity, Result,
 };
-use rust_decimal::Decimal;
-use std::str::FromStr;
 
 /// Convert Brix to SG using polynomial approximation.
 #[derive(Default)]
@@ -28,29 +25,43 @@ impl Calculator for BrixToSgCalculator {
         "Convert degrees Brix to specific gravity"
     }
 
-    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
-        let brix_meas = input.get_measurement(Unit::Brix)?;
-        let brix = brix_meas.value;
+    fn category(&self) -> Category {
+        Category::Basic
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["gravity", "conversion"]
+    }
 
-        Validator::brix(brix)?;
+    fn keywords(&self) -> &'static [&'static str] {
+        &["refractometer", "brix", "sg"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        20
+    }
 
-        let sg = Decimal::ONE + (brix * Decimal::new(4, 3)); // 0.004
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        let brix: Brix = input.get_measured()?;
+        let sg = brix.to_sg()?;
 
-        let mut result = CalcResult::new(Measurement::sg(sg)?);
+        let mut result = CalcResult::new(sg.measurement());
 
-        if let Some(warning) = Validator::brix_warning(brix) {
+        if let Some(warning) = brix.warning() {
             result = result.with_warning(warning);
         }
 
+        let working = format!("1 + {} × 0.004", brix.base());
         result = result
-            .with_meta("brix", brix.to_string())
-            .with_meta("formula", "SG ≈ 1.0 + (Brix × 0.004)");
+            .with_meta("brix", brix.base().to_string())
+            .with_meta("formula", "SG ≈ 1.0 + (Brix × 0.004)")
+            .with_step("SG", "1 + Brix × 0.004", working, sg.base());
 
         Ok(result)
     }
 
     fn validate(&self, input: &CalcInput) -> Result<()> {
-        input.get_measurement(Unit::Brix)?;
+        input.get_measured::<Brix>()?;
         Ok(())
     }
 }
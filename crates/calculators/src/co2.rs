@@ -0,0 +1,63 @@
+// Shared CO₂ solubility relations for carbonation calculators.
+
+use mazerion_core::math::exp;
+use mazerion_core::{Error, Result, Temperature};
+use rust_decimal::Decimal;
+
+/// Bottle type with a conservative working pressure limit (psi gauge).
+pub(crate) struct Bottle {
+    pub(crate) key: &'static str,
+    pub(crate) name: &'static str,
+    pub(crate) max_psi: i64,
+}
+
+pub(crate) const BOTTLES: [Bottle; 4] = [
+    Bottle { key: "standard", name: "Standard beer bottle", max_psi: 40 },
+    Bottle { key: "swing_top", name: "Swing-top bottle", max_psi: 50 },
+    Bottle { key: "belgian", name: "Belgian bottle", max_psi: 60 },
+    Bottle { key: "champagne", name: "Champagne bottle", max_psi: 90 },
+];
+
+/// Look up a bottle type by key.
+pub(crate) fn find_bottle(key: &str) -> Result<&'static Bottle> {
+    BOTTLES
+        .iter()
+        .find(|b| b.key == key)
+        .ok_or_else(|| Error::Validation(format!("Unknown bottle type: {}", key)))
+}
+
+/// Residual CO₂ volumes left in solution after fermenting at `temp`.
+pub(crate) fn residual_volumes(temp: Temperature) -> Decimal {
+    let t = temp.as_fahrenheit();
+    Decimal::new(30378, 4) - Decimal::new(50062, 6) * t + Decimal::new(26555, 8) * t * t
+}
+
+/// [`pressure_psi`] with T in °F and V in volumes, for showing working.
+pub(crate) const PRESSURE_FORMULA: &str =
+    "−16.6999 − 0.0101059·T + 0.00116512·T² + 0.173354·T·V + 4.24267·V − 0.0684226·V²";
+
+/// Equilibrium gauge pressure (psi) holding `volumes` of CO₂ at `temp` (Zahm & Nagel fit).
+pub(crate) fn pressure_psi(temp: Temperature, volumes: Decimal) -> Decimal {
+    let t = temp.as_fahrenheit();
+    let v = volumes;
+    Decimal::new(-166999, 4) - Decimal::new(101059, 7) * t + Decimal::new(116512, 8) * t * t
+        + Decimal::new(173354, 6) * t * v
+        + Decimal::new(424267, 5) * v
+        - Decimal::new(684226, 7) * v * v
+}
+
+/// Equilibrium gauge pressure (psi) from Henry's law, p = V / 22.414 / kH, with
+/// kH = 0.034 × e^(2400 × (1/T − 1/298.15)) mol/(L·atm). Unlike the serving-range
+/// [`pressure_psi`] fit it holds at pasteurization temperatures; headspace is ignored.
+pub(crate) fn henry_pressure_psi(temp: Temperature, volumes: Decimal) -> Result<Decimal> {
+    let kelvin = temp.as_celsius() + Decimal::new(27315, 2);
+    let inverse = Decimal::ONE / kelvin - Decimal::ONE / Decimal::new(29815, 2);
+    let solubility = Decimal::new(34, 3) * exp(Decimal::from(2400) * inverse)?;
+    let atm = volumes / Decimal::new(22414, 3) / solubility;
+    Ok((atm - Decimal::ONE) * Decimal::new(14696, 3))
+}
+
+/// psi to bar.
+pub(crate) fn psi_to_bar(psi: Decimal) -> Decimal {
+    psi * Decimal::new(689476, 7)
+}
@@ -0,0 +1,148 @@
+// Beer and braggot colour (MCU, SRM, EBC) from a grain bill.
+
+use mazerion_core::{
+    math, register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement,
+    Result, Unit, Volume,
+};
+use rust_decimal::prelude::*;
+
+/// EBC per SRM.
+const EBC_PER_SRM: Decimal = Decimal::from_parts(197, 0, 0, false, 2);
+
+/// Colour name thresholds by upper SRM bound.
+#[rustfmt::skip]
+const DESCRIPTIONS: [(i64, &str); 6] = [
+    (4, "pale straw"), (9, "gold"), (14, "amber"),
+    (20, "copper"), (30, "brown"), (i64::MAX, "black"),
+];
+
+/// Parse a `kg:colour` comma-separated grain list.
+fn parse_grains(raw: &str) -> Result<Vec<(Decimal, Decimal)>> {
+    raw.split(',')
+        .map(|segment| {
+            let (kg, colour) = segment
+                .split_once(':')
+                .ok_or_else(|| Error::Parse(format!("Expected kg:colour, got '{}'", segment)))?;
+            let parse = |s: &str| {
+                Decimal::from_str(s.trim())
+                    .map_err(|_| Error::Parse(format!("Invalid grain value: {}", s)))
+            };
+            let (kg, colour) = (parse(kg)?, parse(colour)?);
+            if kg < Decimal::ZERO || colour < Decimal::ZERO {
+                return Err(Error::Validation("Grain weight and colour cannot be negative".into()));
+            }
+            Ok((kg, colour))
+        })
+        .collect()
+}
+
+/// Approximate sRGB hex for a beer colour, using per-channel exponential decay.
+pub fn srm_to_srgb(srm: Decimal) -> String {
+    let channel = |base: i64| match math::pow(Decimal::new(base, 3), srm) {
+        Ok(f) => (f * Decimal::from(255)).round().to_u8().unwrap_or(0),
+        Err(_) => 0,
+    };
+    format!("#{:02X}{:02X}{:02X}", channel(975), channel(880), channel(700))
+}
+
+/// Calculate MCU, SRM (Morey) and EBC with an sRGB swatch.
+#[derive(Default)]
+pub struct ColourCalculator;
+
+impl ColourCalculator {
+    pub const ID: &'static str = "colour";
+}
+
+impl Calculator for ColourCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["color"]
+    }
+
+    fn name(&self) -> &'static str {
+        "Colour (SRM/EBC)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Estimate beer colour from grain weights and Lovibond or EBC ratings"
+    }
+
+    fn category(&self) -> Category {
+        Category::Brewing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["colour", "beer"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["srm", "ebc", "lovibond"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        50
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let grains = parse_grains(input.get_param("grains").unwrap_or_default())?;
+        let volume = input.get_quantity::<Volume>("volume")?.as_liters();
+        let scale = input.get_param("colour_unit").unwrap_or("lovibond");
+        let to_lovibond = |colour: Decimal| match scale {
+            "lovibond" => Ok(colour),
+            // EBC → SRM, then SRM = 1.3546 × °L − 0.76.
+            "ebc" => Ok((colour / EBC_PER_SRM + Decimal::new(76, 2)) / Decimal::new(13546, 4)),
+            other => Err(Error::Validation(format!("Unknown colour unit: {}", other))),
+        };
+
+        // MCU = Σ(lb × °L) / US gal = 8.3454 × Σ(kg × °L) / L.
+        let mut weighted = Decimal::ZERO;
+        for (kg, colour) in &grains {
+            weighted += kg * to_lovibond(*colour)?;
+        }
+        let mcu = Decimal::new(83454, 4) * weighted / volume;
+
+        let srm = Decimal::new(14922, 4) * math::pow(mcu, Decimal::new(6859, 4))?;
+        let ebc = srm * EBC_PER_SRM;
+        let mcu_working = format!("8.3454 × {} / {}", weighted, volume);
+        let srm_working = format!("1.4922 × {}^0.6859", mcu.round_dp(2));
+        let swatch = srm_to_srgb(srm);
+        let description = DESCRIPTIONS.iter().find(|(max, _)| srm <= Decimal::from(*max));
+        let description = description.map_or("black", |(_, name)| name);
+
+        let mut result = CalcResult::new(Measurement::new(srm, Unit::Srm))
+            .with_secondary("EBC", ebc.round_dp(1), Unit::Ebc)
+            .with_secondary("MCU", mcu.round_dp(1), Unit::Mcu);
+        if srm > Decimal::from(50) {
+            result = result.with_warning("Morey equation is unreliable above ~50 SRM");
+        }
+
+        Ok(result
+            .with_meta("srgb", swatch)
+            .with_meta("description", description)
+            .with_meta("colour_unit", scale)
+            .with_meta("formula", "SRM = 1.4922 × MCU^0.6859; EBC = 1.97 × SRM")
+            .with_step("MCU", "8.3454 × Σ(kg × °L) / L", mcu_working, mcu)
+            .with_step("SRM", "1.4922 × MCU^0.6859", srm_working, srm)
+            .with_step("EBC", "SRM × 1.97", format!("{} × 1.97", srm.round_dp(2)), ebc))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        for key in ["grains", "volume"] {
+            if input.get_param(key).is_none() {
+                return Err(Error::MissingInput(format!("{} required", key)));
+            }
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(ColourCalculator);
+
+#[cfg(test)]
+#[path = "colour_tests.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+use super::*;
+use crate::test_support::{meta, params};
+
+fn input(grains: &str) -> CalcInput {
+    params(&[("grains", grains), ("volume", "20")])
+}
+
+#[test]
+fn test_pale_grain_bill() -> Result<()> {
+    let result = ColourCalculator.calculate(input("4:2"))?;
+    assert_eq!(result.output.unit, Unit::Srm);
+    assert_eq!(result.output.value.round_dp(1), Decimal::new(34, 1));
+    assert_eq!(result.secondary[1].value, Decimal::new(33, 1));
+    assert_eq!(meta(&result, "description"), Some("pale straw"));
+    assert_eq!(result.trace[0].substituted, "8.3454 × 8 / 20");
+    Ok(())
+}
+
+#[test]
+fn test_ebc_input_matches_lovibond() -> Result<()> {
+    let lovibond = ColourCalculator.calculate(input("4:2, 0.3:120"))?;
+    // 2 °L ≈ 3.9 EBC and 120 °L ≈ 318.7 EBC.
+    let ebc = ColourCalculator.calculate(
+        input("4:3.8373, 0.3:318.7231").add_param("colour_unit", "ebc"),
+    )?;
+    assert_eq!(lovibond.output.value.round_dp(2), ebc.output.value.round_dp(2));
+    Ok(())
+}
+
+#[test]
+fn test_dark_beer_swatch() -> Result<()> {
+    let result = ColourCalculator.calculate(input("5:3, 0.5:500"))?;
+    assert!(result.output.value > Decimal::from(30));
+    assert_eq!(meta(&result, "description"), Some("black"));
+    let ebc = result.output.value * EBC_PER_SRM;
+    assert_eq!(result.secondary[0].value, ebc.round_dp(1));
+    Ok(())
+}
+
+#[test]
+fn test_srgb_swatch() {
+    assert_eq!(srm_to_srgb(Decimal::ZERO), "#FFFFFF");
+    assert_eq!(srm_to_srgb(Decimal::from(10)), "#C64707");
+}
+
+#[test]
+fn test_unknown_colour_unit() {
+    let result = ColourCalculator.calculate(input("4:2").add_param("colour_unit", "mcu"));
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
+
+#[test]
+fn test_missing_grains() {
+    let input = CalcInput::new().add_param("volume", "20");
+    assert!(matches!(ColourCalculator.calculate(input), Err(Error::MissingInput(_))));
+}
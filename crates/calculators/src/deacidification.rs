@@ -0,0 +1,142 @@
+// Deacidification calculator for lowering titratable acidity.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Result,
+    Unit, Validator, Volume,
+};
+use rust_decimal::Decimal;
+
+/// Deacidifying agent with its dose per g/L of TA removed (as tartaric).
+struct Agent {
+    key: &'static str,
+    name: &'static str,
+    grams_per_g_ta: Decimal,
+}
+
+const AGENTS: [Agent; 3] = [
+    Agent {
+        key: "potassium_bicarbonate",
+        name: "Potassium bicarbonate (KHCO₃)",
+        grams_per_g_ta: Decimal::from_parts(90, 0, 0, false, 2), // 0.90
+    },
+    Agent {
+        key: "calcium_carbonate",
+        name: "Calcium carbonate (CaCO₃)",
+        grams_per_g_ta: Decimal::from_parts(67, 0, 0, false, 2), // 0.67
+    },
+    Agent {
+        key: "potassium_carbonate",
+        name: "Potassium carbonate (K₂CO₃)",
+        grams_per_g_ta: Decimal::from_parts(92, 0, 0, false, 2), // 0.92
+    },
+];
+
+/// Calculate carbonate additions to reduce titratable acidity.
+#[derive(Default)]
+pub struct DeacidificationCalculator;
+
+impl DeacidificationCalculator {
+    pub const ID: &'static str = "deacidification";
+}
+
+impl Calculator for DeacidificationCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Deacidification"
+    }
+
+    fn description(&self) -> &'static str {
+        "Calculate carbonate additions to reduce titratable acidity (TA)"
+    }
+
+    fn category(&self) -> Category {
+        Category::Finishing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["acid", "ta"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["potassium bicarbonate", "calcium carbonate", "ph"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        10
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let volume = input.get_quantity::<Volume>("volume")?.as_liters();
+        let current_ta = input.get_decimal("current_ta")?;
+        let target_ta = input.get_decimal("target_ta")?;
+        let agent_key = input.get_param("agent").unwrap_or("potassium_bicarbonate");
+
+        if target_ta <= Decimal::ZERO {
+            return Err(Error::Validation("Target TA must be positive".into()));
+        }
+        if current_ta <= target_ta {
+            return Err(Error::Validation("Current TA must be greater than target TA".into()));
+        }
+
+        let agent = AGENTS
+            .iter()
+            .find(|a| a.key == agent_key)
+            .ok_or_else(|| Error::Validation(format!("Unknown agent: {}", agent_key)))?;
+
+        let reduction = current_ta - target_ta;
+        let grams = reduction * agent.grams_per_g_ta * volume;
+        // Rule of thumb: each 1 g/L of TA removed raises pH by roughly 0.1.
+        let ph_rise = reduction * Decimal::new(1, 1);
+
+        let mut result = CalcResult::new(Measurement::new(grams, Unit::Grams));
+
+        if reduction > Decimal::from(3) {
+            result = result.with_warning(
+                "TA reduction > 3 g/L risks a chalky, flat taste; consider blending or cold stabilization",
+            );
+        }
+
+        let drop = format!("{} − {}", current_ta, target_ta);
+        let dose = format!("{} × {} × {}", reduction, agent.grams_per_g_ta, volume);
+        result = result
+            .with_meta("agent", agent.name)
+            .with_meta("ta_reduction", format!("{} g/L", reduction.round_dp(2)))
+            .with_meta("ph_rise", format!("+{}", ph_rise.round_dp(2)))
+            .with_step("TA reduction", "current TA − target TA", drop, reduction)
+            .with_step(agent.name, "reduction × g per g/L of TA × volume", dose, grams)
+            .with_step("pH rise", "reduction × 0.1", format!("{} × 0.1", reduction), ph_rise);
+
+        if input.get_param("current_ph").is_some() {
+            let ph = input.get_decimal("current_ph")?;
+            Validator::ph(ph)?;
+            result = result.with_meta("estimated_ph", (ph + ph_rise).round_dp(2).to_string());
+        }
+
+        for other in AGENTS.iter().filter(|a| a.key != agent.key) {
+            let grams = reduction * other.grams_per_g_ta * volume;
+            result = result.with_meta(other.key, format!("{} g", grams.round_dp(2)));
+        }
+
+        Ok(result.with_meta("formula", "grams = (TA current − TA target) × factor × volume"))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        for key in ["volume", "current_ta", "target_ta"] {
+            if input.get_param(key).is_none() {
+                return Err(Error::MissingInput(format!("{} required", key)));
+            }
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(DeacidificationCalculator);
+
+#[cfg(test)]
+#[path = "deacidification_tests.rs"]
+mod tests;
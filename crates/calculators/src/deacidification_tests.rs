@@ -0,0 +1,60 @@
+use super::*;
+use crate::test_support::{meta, params};
+
+fn input(volume: &str, current: &str, target: &str) -> CalcInput {
+    params(&[("volume", volume), ("current_ta", current), ("target_ta", target)])
+}
+
+#[test]
+fn test_potassium_bicarbonate_default() -> Result<()> {
+    let result = DeacidificationCalculator.calculate(input("20", "9", "7"))?;
+    // 2 g/L × 0.90 × 20 L
+    assert_eq!(result.output.value, Decimal::from(36));
+    assert_eq!(result.output.unit, Unit::Grams);
+    assert_eq!(meta(&result, "ph_rise"), Some("+0.2"));
+    assert_eq!(result.trace[1].substituted, "2 × 0.90 × 20");
+    assert!(result.warnings.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_calcium_carbonate() -> Result<()> {
+    let calc_input = input("10", "8", "6").add_param("agent", "calcium_carbonate");
+    let result = DeacidificationCalculator.calculate(calc_input)?;
+    assert_eq!(result.output.value, Decimal::new(134, 1));
+    assert!(meta(&result, "potassium_bicarbonate").is_some());
+    Ok(())
+}
+
+#[test]
+fn test_estimated_ph() -> Result<()> {
+    let calc_input = input("10", "8", "6").add_param("current_ph", "3.10");
+    let result = DeacidificationCalculator.calculate(calc_input)?;
+    assert_eq!(meta(&result, "estimated_ph"), Some("3.30"));
+    Ok(())
+}
+
+#[test]
+fn test_large_reduction_warns() -> Result<()> {
+    let result = DeacidificationCalculator.calculate(input("20", "11", "7"))?;
+    assert_eq!(result.warnings.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_target_above_current() {
+    let result = DeacidificationCalculator.calculate(input("20", "6", "7"));
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
+
+#[test]
+fn test_unknown_agent() {
+    let calc_input = input("20", "9", "7").add_param("agent", "chalk_dust");
+    assert!(DeacidificationCalculator.calculate(calc_input).is_err());
+}
+
+#[test]
+fn test_missing_input() {
+    let result = DeacidificationCalculator.calculate(CalcInput::new());
+    assert!(matches!(result, Err(Error::MissingInput(_))));
+}
@@ -0,0 +1,106 @@
+// Fining agent dosing with slurry preparation and ordered steps.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Result,
+    Unit, Volume,
+};
+
+use crate::fining_agents::find_agent;
+
+/// Calculate fining agent additions and slurry water for a batch.
+#[derive(Default)]
+pub struct FiningCalculator;
+
+impl FiningCalculator {
+    pub const ID: &'static str = "fining";
+}
+
+impl Calculator for FiningCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Fining Agents"
+    }
+
+    fn description(&self) -> &'static str {
+        "Calculate bentonite, kieselsol/chitosan, Sparkolloid or gelatin doses and slurry water"
+    }
+
+    fn category(&self) -> Category {
+        Category::Finishing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["clarity", "fining"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["bentonite", "chitosan", "kieselsol", "sparkolloid", "gelatin"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        30
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let volume: Volume = input.get_quantity("volume")?;
+
+        let agent_key = input.get_param("agent").unwrap_or("bentonite");
+        let agent = find_agent(agent_key)?;
+
+        let level = input.get_param("level").unwrap_or("medium");
+        let index = match level {
+            "low" => 0,
+            "medium" => 1,
+            "high" => 2,
+            other => return Err(Error::Validation(format!("Unknown dose level: {}", other))),
+        };
+
+        let hectoliters = volume.as_hectoliters();
+        let first = agent.steps[0].dose_g_hl[index] * hectoliters;
+        let mut result = CalcResult::new(Measurement::new(first, Unit::Grams))
+            .with_meta("agent", agent.name)
+            .with_meta("level", level);
+
+        for (n, step) in agent.steps.iter().enumerate() {
+            let dose = step.dose_g_hl[index] * hectoliters;
+            let water = dose * step.water_ml_per_g;
+            let water_ml = format!("{} mL", water.round_dp(0));
+            let prep = if water.is_zero() {
+                String::new()
+            } else {
+                format!(" in {} water", water_ml)
+            };
+            let amount = format!("{} g {}{}", dose.round_dp(2).normalize(), step.component, prep);
+            let dose_working = format!("{} × {} / 100", step.dose_g_hl[index], volume.as_liters());
+            result = result
+                .with_step(step.component, "dose (g/hL) × volume (L) / 100", dose_working, dose)
+                .with_note(format!("Step {}: {}; {}", n + 1, amount, step.instruction))
+                .with_meta(step.component, format!("{} g/hL", step.dose_g_hl[index]))
+                .with_meta(format!("{}_water", step.component), water_ml);
+        }
+
+        if agent.key == "bentonite" && index == 2 {
+            result = result.with_warning("High bentonite doses can strip aroma and colour");
+        }
+
+        Ok(result.with_meta("formula", "grams = dose (g/hL) × volume (L) / 100"))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        if input.get_param("volume").is_none() {
+            return Err(Error::MissingInput("volume required".into()));
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(FiningCalculator);
+
+#[cfg(test)]
+#[path = "fining_tests.rs"]
+mod tests;
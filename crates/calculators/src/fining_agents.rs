@@ -0,0 +1,66 @@
+// Fining agent dose table shared by the fining calculator.
+
+use mazerion_core::{Error, Result};
+use rust_decimal::Decimal;
+
+/// One addition of a fining agent: dose (low/medium/high g/hL) and slurry water.
+pub(crate) struct Step {
+    pub(crate) component: &'static str,
+    pub(crate) dose_g_hl: [Decimal; 3],
+    pub(crate) water_ml_per_g: Decimal,
+    pub(crate) instruction: &'static str,
+}
+
+const fn grams(value: u32) -> Decimal {
+    Decimal::from_parts(value, 0, 0, false, 0)
+}
+
+const fn step(component: &'static str, dose: [u32; 3], water: u32, how: &'static str) -> Step {
+    Step {
+        component,
+        dose_g_hl: [grams(dose[0]), grams(dose[1]), grams(dose[2])],
+        water_ml_per_g: grams(water),
+        instruction: how,
+    }
+}
+
+/// Fining agent made of one or more ordered additions.
+pub(crate) struct Agent {
+    pub(crate) key: &'static str,
+    pub(crate) name: &'static str,
+    pub(crate) steps: &'static [Step],
+}
+
+const AGENTS: [Agent; 4] = [
+    Agent {
+        key: "bentonite",
+        name: "Bentonite",
+        steps: &[step("bentonite", [25, 50, 100], 16, "hydrate in hot water 12–24 h, stir in")],
+    },
+    Agent {
+        key: "chitosan_kieselsol",
+        name: "Kieselsol + Chitosan",
+        steps: &[
+            step("kieselsol", [25, 50, 100], 0, "stir in gently, wait 1 h"),
+            step("chitosan", [5, 10, 20], 10, "dissolve in cool water and stir in gently"),
+        ],
+    },
+    Agent {
+        key: "sparkolloid",
+        name: "Sparkolloid",
+        steps: &[step("Sparkolloid", [10, 20, 40], 50, "simmer 15–20 min, add while hot")],
+    },
+    Agent {
+        key: "gelatin",
+        name: "Gelatin",
+        steps: &[step("gelatin", [3, 6, 10], 20, "bloom in cool water, warm to ~50°C, stir in")],
+    },
+];
+
+/// Look up a fining agent by key.
+pub(crate) fn find_agent(key: &str) -> Result<&'static Agent> {
+    AGENTS
+        .iter()
+        .find(|a| a.key == key)
+        .ok_or_else(|| Error::Validation(format!("Unknown fining agent: {}", key)))
+}
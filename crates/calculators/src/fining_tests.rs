@@ -0,0 +1,54 @@
+use super::*;
+use rust_decimal::Decimal;
+use crate::test_support::params;
+
+fn input(volume: &str, agent: &str, level: &str) -> CalcInput {
+    params(&[("volume", volume), ("agent", agent), ("level", level)])
+}
+
+#[test]
+fn test_bentonite_medium() -> Result<()> {
+    let result = FiningCalculator.calculate(input("20", "bentonite", "medium"))?;
+    // 50 g/hL × 0.2 hL
+    assert_eq!(result.output.value, Decimal::from(10));
+    assert_eq!(result.output.unit, Unit::Grams);
+    assert_eq!(result.notes.len(), 1);
+    assert!(result.notes[0].contains("160 mL water"));
+    Ok(())
+}
+
+#[test]
+fn test_defaults_to_medium_bentonite() -> Result<()> {
+    let result = FiningCalculator.calculate(CalcInput::new().add_param("volume", "100"))?;
+    assert_eq!(result.output.value, Decimal::from(50));
+    Ok(())
+}
+
+#[test]
+fn test_two_part_agent_ordered_steps() -> Result<()> {
+    let result = FiningCalculator.calculate(input("20", "chitosan_kieselsol", "low"))?;
+    assert_eq!(result.notes.len(), 2);
+    assert!(result.notes[0].starts_with("Step 1: 5 g kieselsol"));
+    assert!(result.notes[1].starts_with("Step 2: 1 g chitosan"));
+    Ok(())
+}
+
+#[test]
+fn test_high_bentonite_warns() -> Result<()> {
+    let result = FiningCalculator.calculate(input("20", "bentonite", "high"))?;
+    assert_eq!(result.output.value, Decimal::from(20));
+    assert_eq!(result.warnings.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_unknown_agent_and_level() {
+    assert!(FiningCalculator.calculate(input("20", "egg_whites", "low")).is_err());
+    assert!(FiningCalculator.calculate(input("20", "gelatin", "extreme")).is_err());
+}
+
+#[test]
+fn test_invalid_volume() {
+    let result = FiningCalculator.calculate(input("-5", "gelatin", "low"));
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
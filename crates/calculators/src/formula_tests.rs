@@ -0,0 +1,55 @@
+use mazerion_config::{load_formulas, register_formulas, FormulaWatcher};
+use mazerion_core::{find_handle, CalcInput, Error, Measurement, Result, Unit};
+use rust_decimal::Decimal;
+use std::fs;
+
+fn run(id: &str, input: CalcInput) -> Result<Decimal> {
+    let calc = find_handle(id).ok_or(Error::Config(format!("{} not registered", id)))?;
+    Ok(calc.calculate(input)?.output.value)
+}
+
+fn write(path: &std::path::Path, content: &str) -> Result<()> {
+    fs::write(path, content).map_err(|e| Error::Io(e.to_string()))
+}
+
+// User calculators are global, so file loading and hot reload share one test.
+#[test]
+fn test_formula_file_and_hot_reload() -> Result<()> {
+    crate::init()?;
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../formulas.toml");
+    assert_eq!(register_formulas(load_formulas(path)?)?, 2);
+
+    let abv = run("abv_hall", CalcInput::new().add_param("og", "1.100").add_param("fg", "1.010"))?;
+    assert_eq!(abv.round_dp(2), Decimal::new(1290, 2));
+    let brix = Measurement::new(Decimal::from(24), Unit::Brix);
+    assert_eq!(run("tosna_yan", CalcInput::new().add_measurement(brix))?, Decimal::from(216));
+    let clash = "[[calculators]]\nid = \"abv\"\nname = \"x\"\n\
+                 expression = \"1\"\nunit = \"Abv\"\ninputs = []";
+    assert!(register_formulas(mazerion_config::parse_formulas(clash)?).is_err());
+    assert!(find_handle("abv_hall").is_some());
+
+    let file = std::env::temp_dir().join(format!("mazerion_formulas_{}.toml", std::process::id()));
+    let formula = |expression: &str| {
+        format!(
+            "[[calculators]]\nid = \"house_double\"\nname = \"Double\"\n\
+             expression = \"{}\"\nunit = \"Liters\"\n[[calculators.inputs]]\nname = \"x\"\n",
+            expression
+        )
+    };
+    write(&file, &formula("x * 2"))?;
+    let mut watcher = FormulaWatcher::new(&file);
+    assert_eq!(watcher.poll()?, Some(1));
+    assert_eq!(watcher.poll()?, None);
+    assert!(find_handle("abv_hall").is_none());
+    assert_eq!(run("house_double", CalcInput::new().add_param("x", "4"))?, Decimal::from(8));
+
+    write(&file, &formula("x * 2 + 100"))?;
+    assert_eq!(watcher.poll()?, Some(1));
+    assert_eq!(run("house_double", CalcInput::new().add_param("x", "4"))?, Decimal::from(108));
+
+    write(&file, &formula("x *"))?;
+    assert!(matches!(watcher.poll(), Err(Error::Config(_))));
+    assert_eq!(run("house_double", CalcInput::new().add_param("x", "1"))?, Decimal::from(102));
+    let _ = fs::remove_file(&file);
+    mazerion_core::register_user_calculators(Vec::new())
+}
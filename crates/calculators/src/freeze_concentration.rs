@@ -0,0 +1,150 @@
+// Freeze concentration ("jacking") estimator for ice meads and ciders.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Quantity,
+    Result, Sg, Unit, Validator, Volume,
+};
+use rust_decimal::Decimal;
+
+const ASSUMPTIONS: &str = "removed ice is pure water with no trapped must; \
+    volumes are additive; ethanol lowers SG by 0.00133 per % ABV; \
+    freezing point ≈ −(0.4 × ABV + 0.1 × extract °Bx)";
+
+/// Estimated freezing point (°C) for a given ABV and dissolved extract gravity points.
+fn freezing_point(abv: Decimal, extract_points: Decimal) -> Decimal {
+    let extract_brix = extract_points * Decimal::from(250); // 4 gravity points per °Bx
+    -(Decimal::new(4, 1) * abv + Decimal::new(1, 1) * extract_brix)
+}
+
+/// Estimate ABV, SG and volume after removing ice, or the ice to remove for a target ABV.
+#[derive(Default)]
+pub struct FreezeConcentrationCalculator;
+
+impl FreezeConcentrationCalculator {
+    pub const ID: &'static str = "freeze_concentration";
+}
+
+impl Calculator for FreezeConcentrationCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Freeze Concentration"
+    }
+
+    fn description(&self) -> &'static str {
+        "Estimate ABV, SG and volume after removing ice, or ice to remove for a target ABV"
+    }
+
+    fn category(&self) -> Category {
+        Category::Advanced
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["concentration", "alcohol"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["jacking", "ice", "applejack", "eisbock"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        30
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let volume = input.get_quantity::<Volume>("volume")?.as_liters();
+        let abv = input.get_decimal("abv")?;
+        let sg = input.get_quantity::<Sg>("sg")?.base();
+        let freezer = input.get_decimal_or("freezer_temp", Decimal::from(-18))?;
+        Validator::percent(abv)?;
+        if freezer >= Decimal::ZERO || freezer < Decimal::from(-60) {
+            return Err(Error::Validation("Freezer temperature must be −60–0 °C".into()));
+        }
+
+        let alcohol_factor = Decimal::new(133, 5);
+        let extract_points = (sg - Decimal::ONE + abv * alcohol_factor).max(Decimal::ZERO);
+        // Ice forms until the liquid's freezing point, initial / (1 − f), meets the freezer.
+        let initial_freeze = freezing_point(abv, extract_points);
+        let max_fraction = (Decimal::ONE - initial_freeze / freezer).max(Decimal::ZERO);
+
+        let backwards = input.get_param("target_abv").is_some();
+        let fraction = if backwards {
+            let target = input.get_decimal("target_abv")?;
+            if target <= abv || target > Decimal::from(40) {
+                return Err(Error::Validation("Target ABV must exceed current ABV, ≤ 40%".into()));
+            }
+            Decimal::ONE - abv / target
+        } else if input.get_param("ice_mass").is_some() {
+            // 1 kg of ice melts to ~1 L of water.
+            input.get_decimal("ice_mass")? / volume
+        } else {
+            input.get_decimal("ice_fraction")?
+        };
+        if fraction <= Decimal::ZERO || fraction >= Decimal::ONE || fraction > max_fraction {
+            let max = max_fraction.round_dp(3);
+            let msg = format!("Ice fraction {} outside 0–{} at {} °C", fraction, max, freezer);
+            return Err(Error::OutOfRange(msg));
+        }
+
+        let remaining = Decimal::ONE - fraction;
+        let new_abv = abv / remaining;
+        let new_points = extract_points / remaining;
+        let new_sg = Decimal::ONE + new_points - new_abv * alcohol_factor;
+        let new_volume = volume * remaining;
+        let freeze_temp = freezing_point(new_abv, new_points);
+
+        let percent = fraction * Decimal::ONE_HUNDRED;
+        let mut result = if backwards {
+            CalcResult::new(Measurement::new(percent, Unit::Percent))
+                .with_secondary("Resulting ABV", new_abv.round_dp(2), Unit::Abv)
+        } else {
+            CalcResult::new(Measurement::new(new_abv, Unit::Abv))
+                .with_secondary("Ice removed", percent.round_dp(1), Unit::Percent)
+        };
+        result = result
+            .with_secondary("Resulting SG", new_sg.round_dp(4), Unit::SpecificGravity)
+            .with_secondary("Resulting volume", new_volume.round_dp(2), Unit::Liters)
+            .with_secondary("Ice to remove", (volume - new_volume).round_dp(2), Unit::Liters)
+            .with_secondary("Freezing point", freeze_temp.round_dp(1), Unit::Celsius);
+
+        if fraction > Decimal::new(75, 2) {
+            result = result.with_warning("Removing > 75% as ice is rarely achievable in practice");
+        }
+
+        let f = fraction.round_dp(4);
+        let extract = format!("{} − 1 + {} × 0.00133", sg, abv);
+        let abv_term = new_abv.round_dp(2);
+        let sg_working = format!("1 + {} / (1 − {}) − {} × 0.00133", extract_points, f, abv_term);
+        Ok(result
+            .with_meta("initial_freezing_point", format!("{} °C", initial_freeze.round_dp(1)))
+            .with_meta("freezer_temp", format!("{} °C", freezer))
+            .with_meta("assumptions", ASSUMPTIONS)
+            .with_meta("formula", "ABV₁ = ABV₀ / (1 − f); points₁ = points₀ / (1 − f)")
+            .with_step("Extract", "SG − 1 + ABV × 0.00133", extract, extract_points)
+            .with_step("ABV", "ABV / (1 − f)", format!("{} / (1 − {})", abv, f), new_abv)
+            .with_step("SG", "1 + extract / (1 − f) − ABV × 0.00133", sg_working, new_sg))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        for key in ["volume", "abv", "sg"] {
+            if input.get_param(key).is_none() {
+                return Err(Error::MissingInput(format!("{} required", key)));
+            }
+        }
+        let has = |key: &str| input.get_param(key).is_some();
+        if !has("ice_fraction") && !has("ice_mass") && !has("target_abv") {
+            return Err(Error::MissingInput("ice_fraction, ice_mass or target_abv required".into()));
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(FreezeConcentrationCalculator);
+
+#[cfg(test)]
+#[path = "freeze_concentration_tests.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+use super::*;
+use crate::test_support::{params, secondary};
+
+fn input(ice_key: &str, ice: &str) -> CalcInput {
+    params(&[("volume", "20"), ("abv", "12"), ("sg", "1.000"), (ice_key, ice)])
+}
+
+#[test]
+fn test_remove_quarter_as_ice() -> Result<()> {
+    let result = FreezeConcentrationCalculator.calculate(input("ice_fraction", "0.25"))?;
+    assert_eq!(result.output.unit, Unit::Abv);
+    assert_eq!(result.output.value, Decimal::from(16));
+    assert_eq!(secondary(&result, "Resulting volume"), Some(Decimal::from(15)));
+    assert_eq!(secondary(&result, "Resulting SG"), Some(Decimal::ONE));
+    assert_eq!(secondary(&result, "Freezing point"), Some(Decimal::new(-69, 1)));
+    assert_eq!(result.trace[1].substituted, "12 / (1 − 0.25)");
+    Ok(())
+}
+
+#[test]
+fn test_ice_mass() -> Result<()> {
+    let result = FreezeConcentrationCalculator.calculate(input("ice_mass", "5"))?;
+    assert_eq!(result.output.value, Decimal::from(16));
+    Ok(())
+}
+
+#[test]
+fn test_backwards_from_target_abv() -> Result<()> {
+    let result = FreezeConcentrationCalculator.calculate(input("target_abv", "24"))?;
+    assert_eq!(result.output.unit, Unit::Percent);
+    assert_eq!(result.output.value, Decimal::from(50));
+    assert_eq!(secondary(&result, "Ice to remove"), Some(Decimal::from(10)));
+    Ok(())
+}
+
+#[test]
+fn test_sugar_concentrates() -> Result<()> {
+    let sweet = CalcInput::new()
+        .add_param("volume", "20")
+        .add_param("abv", "12")
+        .add_param("sg", "1.010")
+        .add_param("ice_fraction", "0.5");
+    let result = FreezeConcentrationCalculator.calculate(sweet)?;
+    assert_eq!(secondary(&result, "Resulting SG"), Some(Decimal::new(102, 2)));
+    Ok(())
+}
+
+#[test]
+fn test_extreme_removal_warns() -> Result<()> {
+    let deep_freeze = input("ice_fraction", "0.8").add_param("freezer_temp", "-30");
+    let result = FreezeConcentrationCalculator.calculate(deep_freeze)?;
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(secondary(&result, "Freezing point"), Some(Decimal::from(-26)));
+    assert!(result.metadata.iter().any(|(k, _)| k == "assumptions"));
+    Ok(())
+}
+
+#[test]
+fn test_freezer_caps_ice_fraction() {
+    // This must freezes at −5.2 °C; at −8 °C only 1 − 5.2 / 8 = 35% can become ice.
+    for (key, value) in [("ice_fraction", "0.5"), ("target_abv", "24")] {
+        let calc_input = input(key, value).add_param("freezer_temp", "-8");
+        assert!(matches!(
+            FreezeConcentrationCalculator.calculate(calc_input),
+            Err(Error::OutOfRange(_))
+        ));
+    }
+    let cold_enough = input("ice_fraction", "0.35").add_param("freezer_temp", "-8");
+    assert!(FreezeConcentrationCalculator.calculate(cold_enough).is_ok());
+    let warm = input("ice_fraction", "0.25").add_param("freezer_temp", "4");
+    assert!(matches!(
+        FreezeConcentrationCalculator.calculate(warm),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_invalid_fraction_and_target() {
+    assert!(matches!(
+        FreezeConcentrationCalculator.calculate(input("ice_fraction", "1")),
+        Err(Error::OutOfRange(_))
+    ));
+    assert!(matches!(
+        FreezeConcentrationCalculator.calculate(input("target_abv", "10")),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_missing_ice_input() {
+    let calc_input = CalcInput::new()
+        .add_param("volume", "20")
+        .add_param("abv", "12")
+        .add_param("sg", "1.000");
+    assert!(matches!(
+        FreezeConcentrationCalculator.calculate(calc_input),
+        Err(Error::MissingInput(_))
+    ));
+}
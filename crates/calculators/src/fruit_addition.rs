@@ -0,0 +1,134 @@
+// Fruit additions: gravity, acidity, volume and ABV effects for melomels and ciders.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Result,
+    Sg, Unit, Validator, Volume,
+};
+use rust_decimal::Decimal;
+
+use crate::fruits::find_fruit;
+
+/// Gravity points × litres contributed per kg of fruit sugar (46 PPG).
+const POINTS_PER_KG: i64 = 384;
+
+/// Predict gravity, TA and volume after adding fruit, and the ABV effect in secondary.
+#[derive(Default)]
+pub struct FruitAdditionCalculator;
+
+impl FruitAdditionCalculator {
+    pub const ID: &'static str = "fruit_addition";
+}
+
+impl Calculator for FruitAdditionCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Fruit Addition"
+    }
+
+    fn description(&self) -> &'static str {
+        "Gravity, acidity, volume and ABV contribution of fruit in primary or secondary"
+    }
+
+    fn category(&self) -> Category {
+        Category::Brewing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["fruit", "melomel", "cider"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["berries", "acidity", "pyment"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        20
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let fruit = find_fruit(input.get_param("fruit").unwrap_or_default())?;
+        let mass = input.get_decimal("fruit_mass")?;
+        let volume = input.get_quantity::<Volume>("volume")?.as_liters();
+        let sg: Sg = input.get_quantity("sg")?;
+        let ta = input.get_decimal_or("ta", Decimal::ZERO)?;
+        if mass <= Decimal::ZERO || ta < Decimal::ZERO {
+            return Err(Error::Validation("Fruit mass must be positive, TA non-negative".into()));
+        }
+
+        let sugar_kg = mass * fruit.sugar_percent() / Decimal::ONE_HUNDRED;
+        // Fruit water joins the must as it breaks down; 1 kg ≈ 1 L.
+        let added = mass * fruit.water_percent() / Decimal::ONE_HUNDRED;
+        let total = volume + added;
+        let fruit_points = sugar_kg * Decimal::from(POINTS_PER_KG) / total;
+        let must_points = sg.points() * volume / total;
+        let new_sg = Decimal::ONE + (must_points + fruit_points) / Decimal::from(1000);
+        let new_ta = (ta * volume + fruit.ta() * added) / total;
+        // ABV ≈ ΔSG × 131.25, assuming the fruit sugar ferments dry.
+        let abv_gain = fruit_points * Decimal::new(13125, 5);
+        // Pulp retains what the press would not release.
+        let retained = (mass * (fruit.water_percent() - fruit.juice_yield())
+            / Decimal::ONE_HUNDRED)
+            .max(Decimal::ZERO);
+
+        let stage = input.get_param("stage").unwrap_or("primary");
+        let mut result = match stage {
+            "primary" => CalcResult::new(Measurement::sg(new_sg)?)
+                .with_secondary("Potential ABV from fruit", abv_gain.round_dp(2), Unit::Abv),
+            "secondary" => {
+                let abv = input.get_decimal("abv")?;
+                Validator::percent(abv)?;
+                let diluted = abv * volume / total;
+                let gravity = new_sg.round_dp(4);
+                CalcResult::new(Measurement::new(diluted + abv_gain, Unit::Abv))
+                    .with_secondary("Gravity after addition", gravity, Unit::SpecificGravity)
+                    .with_secondary("ABV after dilution", diluted.round_dp(2), Unit::Abv)
+                    .with_secondary("ABV from fruit sugar", abv_gain.round_dp(2), Unit::Abv)
+                    .with_note("Fruit sugar in secondary will referment unless stabilized")
+            }
+            other => return Err(Error::Validation(format!("Unknown stage: {}", other))),
+        };
+        let working = |pct: Decimal| format!("{} × {} / 100", mass, pct);
+        let points = format!("384 × {} / {}", sugar_kg, total);
+        let (must, fruit_pts) = (must_points.round_dp(2), fruit_points.round_dp(2));
+        let sg_working = format!("1 + ({} + {}) / 1000", must, fruit_pts);
+        result = result
+            .with_step("Fruit sugar", "kg × sugar% / 100", working(fruit.sugar_percent()), sugar_kg)
+            .with_step("Liquid added", "kg × water% / 100", working(fruit.water_percent()), added)
+            .with_step("Fruit points", "384 × sugar kg / total L", points, fruit_points)
+            .with_step("SG", "1 + (must points + fruit points) / 1000", sg_working, new_sg)
+            .with_secondary("TA", new_ta.round_dp(2), Unit::GramsPerLiter)
+            .with_secondary("Liquid added", added.round_dp(2), Unit::Liters)
+            .with_secondary("Total volume", total.round_dp(2), Unit::Liters)
+            .with_secondary("Lost to pulp on racking", retained.round_dp(2), Unit::Liters);
+
+        if mass / volume > Decimal::new(5, 1) {
+            result = result.with_warning("Over 0.5 kg fruit per litre; expect heavy pulp losses");
+        }
+
+        Ok(result
+            .with_meta("fruit", fruit.name)
+            .with_meta("stage", stage)
+            .with_meta("fruit_sugar", format!("{} kg", sugar_kg.round_dp(2)))
+            .with_meta("formula", "SG = 1 + (points × V + 384 × sugar kg) / (V + water) / 1000"))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        for key in ["fruit", "fruit_mass", "volume", "sg"] {
+            if input.get_param(key).is_none() {
+                return Err(Error::MissingInput(format!("{} required", key)));
+            }
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(FruitAdditionCalculator);
+
+#[cfg(test)]
+#[path = "fruit_addition_tests.rs"]
+mod tests;
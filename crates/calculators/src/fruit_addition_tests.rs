@@ -0,0 +1,65 @@
+use super::*;
+use crate::test_support::{params, secondary};
+
+fn input(stage: &str) -> CalcInput {
+    let fruit = [("fruit", "raspberry"), ("fruit_mass", "5"), ("volume", "20"), ("ta", "6")];
+    params(&fruit).add_param("stage", stage)
+}
+
+#[test]
+fn test_primary_addition() -> Result<()> {
+    let result = FruitAdditionCalculator.calculate(input("primary").add_param("sg", "1.100"))?;
+    assert_eq!(result.output.unit, Unit::SpecificGravity);
+    assert_eq!(result.output.value.round_dp(4), Decimal::new(10858, 4));
+    assert_eq!(secondary(&result, "TA"), Some(Decimal::new(795, 2)));
+    assert_eq!(secondary(&result, "Total volume"), Some(Decimal::new(243, 1)));
+    assert_eq!(secondary(&result, "Lost to pulp on racking"), Some(Decimal::new(8, 1)));
+    Ok(())
+}
+
+#[test]
+fn test_secondary_addition_abv() -> Result<()> {
+    let input = input("secondary").add_param("sg", "0.998").add_param("abv", "12");
+    let result = FruitAdditionCalculator.calculate(input)?;
+    assert_eq!(result.output.unit, Unit::Abv);
+    assert_eq!(result.output.value.round_dp(2), Decimal::new(1033, 2));
+    assert_eq!(secondary(&result, "ABV after dilution"), Some(Decimal::new(988, 2)));
+    assert_eq!(result.notes.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_secondary_requires_abv() {
+    let result = FruitAdditionCalculator.calculate(input("secondary").add_param("sg", "0.998"));
+    assert!(matches!(result, Err(Error::MissingInput(_))));
+}
+
+#[test]
+fn test_heavy_fruit_warning() -> Result<()> {
+    let input = CalcInput::new()
+        .add_param("fruit", "cherry_sour")
+        .add_param("fruit_mass", "12")
+        .add_param("volume", "20")
+        .add_param("sg", "1.090");
+    let result = FruitAdditionCalculator.calculate(input)?;
+    assert_eq!(result.warnings.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_unknown_fruit_and_stage() {
+    let unknown = CalcInput::new()
+        .add_param("fruit", "durian")
+        .add_param("fruit_mass", "1")
+        .add_param("volume", "5")
+        .add_param("sg", "1.1");
+    assert!(matches!(FruitAdditionCalculator.calculate(unknown), Err(Error::Validation(_))));
+    let stage = input("tertiary").add_param("sg", "1.1");
+    assert!(matches!(FruitAdditionCalculator.calculate(stage), Err(Error::Validation(_))));
+}
+
+#[test]
+fn test_non_positive_volume() {
+    let empty = input("primary").add_param("sg", "1.1").set_param("volume", "0");
+    assert!(matches!(FruitAdditionCalculator.calculate(empty), Err(Error::Validation(_))));
+}
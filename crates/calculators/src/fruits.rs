@@ -0,0 +1,64 @@
+// Typical fruit composition for melomel and cider additions.
+
+use mazerion_core::{Error, Result};
+use rust_decimal::Decimal;
+
+/// Fruit composition: sugar and water as % of fresh mass, TA in g/L of juice
+/// (as tartaric) and pressed juice yield as % of fresh mass.
+pub(crate) struct Fruit {
+    pub(crate) key: &'static str,
+    pub(crate) name: &'static str,
+    sugar: i64,
+    ta: i64,
+    juice_yield: i64,
+    water: i64,
+}
+
+impl Fruit {
+    pub(crate) fn sugar_percent(&self) -> Decimal {
+        Decimal::new(self.sugar, 1)
+    }
+
+    pub(crate) fn ta(&self) -> Decimal {
+        Decimal::new(self.ta, 1)
+    }
+
+    pub(crate) fn juice_yield(&self) -> Decimal {
+        Decimal::from(self.juice_yield)
+    }
+
+    pub(crate) fn water_percent(&self) -> Decimal {
+        Decimal::from(self.water)
+    }
+}
+
+const fn fruit(key: &'static str, name: &'static str, v: [i64; 4]) -> Fruit {
+    Fruit { key, name, sugar: v[0], ta: v[1], juice_yield: v[2], water: v[3] }
+}
+
+/// Values per fruit: [sugar % ×10, TA g/L ×10, juice yield %, water %].
+#[rustfmt::skip]
+pub(crate) const FRUITS: [Fruit; 14] = [
+    fruit("apple", "Apple", [104, 60, 70, 86]),
+    fruit("blackberry", "Blackberry", [49, 120, 65, 88]),
+    fruit("blackcurrant", "Blackcurrant", [70, 300, 65, 82]),
+    fruit("blueberry", "Blueberry", [100, 60, 70, 84]),
+    fruit("cherry_sour", "Sour cherry", [85, 170, 70, 86]),
+    fruit("cherry_sweet", "Sweet cherry", [128, 50, 70, 82]),
+    fruit("grape", "Wine grape", [160, 70, 75, 81]),
+    fruit("mango", "Mango", [137, 40, 60, 83]),
+    fruit("peach", "Peach", [84, 50, 70, 89]),
+    fruit("pear", "Pear", [98, 30, 70, 84]),
+    fruit("pineapple", "Pineapple", [100, 80, 65, 86]),
+    fruit("plum", "Plum", [99, 90, 65, 87]),
+    fruit("raspberry", "Raspberry", [44, 170, 70, 86]),
+    fruit("strawberry", "Strawberry", [49, 80, 75, 91]),
+];
+
+/// Look up a fruit by key.
+pub(crate) fn find_fruit(key: &str) -> Result<&'static Fruit> {
+    FRUITS
+        .iter()
+        .find(|f| f.key == key)
+        .ok_or_else(|| Error::Validation(format!("Unknown fruit: {}", key)))
+}
@@ -0,0 +1,32 @@
+// Grain bill extract: kg:PPG lists and gravity points per volume.
+
+use mazerion_core::{Error, Result};
+use rust_decimal::prelude::*;
+
+/// Pounds per kilogram.
+pub(crate) const LB_PER_KG: Decimal = Decimal::from_parts(220462, 0, 0, false, 5);
+/// Litres per US gallon.
+pub(crate) const L_PER_GAL: Decimal = Decimal::from_parts(378541, 0, 0, false, 5);
+
+/// Parse a `kg:ppg` comma-separated list into total (lb × PPG) points·gal.
+pub(crate) fn parse_points(key: &str, raw: &str) -> Result<Decimal> {
+    raw.split(',').try_fold(Decimal::ZERO, |total, segment| {
+        let (kg, ppg) = segment
+            .split_once(':')
+            .ok_or_else(|| Error::Parse(format!("Expected kg:ppg in {}, got '{}'", key, segment)))?;
+        let parse = |s: &str| {
+            Decimal::from_str(s.trim())
+                .map_err(|_| Error::Parse(format!("Invalid {} value: {}", key, s)))
+        };
+        let (kg, ppg) = (parse(kg)?, parse(ppg)?);
+        if kg < Decimal::ZERO || ppg < Decimal::ZERO {
+            return Err(Error::Validation(format!("{} weights and PPG cannot be negative", key)));
+        }
+        Ok(total + kg * LB_PER_KG * ppg)
+    })
+}
+
+/// Gravity of `points_gal` points·gal dissolved in `litres`.
+pub(crate) fn to_sg(points_gal: Decimal, litres: Decimal) -> Decimal {
+    Decimal::ONE + points_gal / (litres / L_PER_GAL) / Decimal::from(1000)
+}
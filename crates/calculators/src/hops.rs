@@ -0,0 +1,58 @@
+// Hop additions and bitterness utilization models.
+
+use mazerion_core::math::{exp, pow};
+use mazerion_core::{Error, Quantity, Result, Sg, Validator};
+use rust_decimal::prelude::*;
+
+/// A single hop addition: alpha acid %, grams and boil minutes.
+pub(crate) struct Addition {
+    pub(crate) alpha: Decimal,
+    pub(crate) grams: Decimal,
+    pub(crate) minutes: Decimal,
+}
+
+/// Parse a `alpha:grams:minutes` comma-separated hop list.
+pub(crate) fn parse_hops(raw: &str) -> Result<Vec<Addition>> {
+    raw.split(',')
+        .map(|segment| {
+            let parts: Vec<&str> = segment.split(':').collect();
+            let [alpha, grams, minutes] = parts[..] else {
+                return Err(Error::Parse(format!("Expected alpha:grams:minutes: '{}'", segment)));
+            };
+            let parse = |s: &str| {
+                Decimal::from_str(s.trim())
+                    .map_err(|_| Error::Parse(format!("Invalid hop value: {}", s)))
+            };
+            let addition = Addition {
+                alpha: parse(alpha)?,
+                grams: parse(grams)?,
+                minutes: parse(minutes)?,
+            };
+            Validator::percent(addition.alpha)?;
+            if addition.grams < Decimal::ZERO || addition.minutes < Decimal::ZERO {
+                return Err(Error::Validation("Hop grams and minutes cannot be negative".into()));
+            }
+            Ok(addition)
+        })
+        .collect()
+}
+
+/// Tinseth utilization: 1.65 × 0.000125^(G − 1) × (1 − e^(−0.04t)) / 4.15.
+pub(crate) fn tinseth_utilization(gravity: Sg, minutes: Decimal) -> Result<Decimal> {
+    let bigness = Decimal::new(165, 2) * pow(Decimal::new(125, 6), gravity.base() - Decimal::ONE)?;
+    let boil_time = (Decimal::ONE - exp(Decimal::new(-4, 2) * minutes)?) / Decimal::new(415, 2);
+    Ok(bigness * boil_time)
+}
+
+/// Rager utilization: (18.11 + 13.86 × tanh((t − 31.32) / 18.27)) / 100.
+pub(crate) fn rager_utilization(minutes: Decimal) -> Result<Decimal> {
+    let x = (minutes - Decimal::new(3132, 2)) / Decimal::new(1827, 2);
+    // tanh is ±1 to 17 places beyond |x| = 20, and e^2x overflows long before long boils end.
+    let tanh = if x.abs() > Decimal::from(20) {
+        x.signum()
+    } else {
+        let e2x = exp(Decimal::TWO * x)?;
+        (e2x - Decimal::ONE) / (e2x + Decimal::ONE)
+    };
+    Ok((Decimal::new(1811, 2) + Decimal::new(1386, 2) * tanh) / Decimal::ONE_HUNDRED)
+}
@@ -0,0 +1,116 @@
+// Beer bitterness (IBU) by Tinseth and Rager from a list of hop additions.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Quantity,
+    Result, Sg, Unit, Volume,
+};
+use rust_decimal::Decimal;
+
+use crate::hops::{parse_hops, rager_utilization, tinseth_utilization};
+
+const FORMULA: &str = "utilization × alpha% × grams × 10 / litres";
+
+/// Calculate IBU by Tinseth (primary) and Rager, with a per-addition breakdown.
+#[derive(Default)]
+pub struct IbuCalculator;
+
+impl IbuCalculator {
+    pub const ID: &'static str = "ibu";
+}
+
+impl Calculator for IbuCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Bitterness (IBU)"
+    }
+
+    fn description(&self) -> &'static str {
+        "IBU by Tinseth and Rager from hop additions, with BU:GU ratio"
+    }
+
+    fn category(&self) -> Category {
+        Category::Brewing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["hops", "bitterness", "beer"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["tinseth", "rager", "bu:gu"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        40
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let hops = parse_hops(input.get_param("hops").unwrap_or_default())?;
+        let gravity: Sg = input.get_quantity("boil_gravity")?;
+        let volume = input.get_quantity::<Volume>("volume")?.as_liters();
+        let og: Sg = input.get_quantity_or("og", gravity.base())?;
+
+        // Rager's gravity adjustment applies above 1.050.
+        let adjustment = Decimal::ONE
+            + ((gravity.base() - Decimal::new(1050, 3)) / Decimal::new(2, 1)).max(Decimal::ZERO);
+
+        let mut tinseth = Decimal::ZERO;
+        let mut rager = Decimal::ZERO;
+        let mut breakdown = Vec::with_capacity(hops.len());
+        for hop in &hops {
+            // mg/L of alpha acid added: alpha% / 100 × grams × 1000 / litres.
+            let mg_l = hop.alpha * hop.grams * Decimal::TEN / volume;
+            let utilization = tinseth_utilization(gravity, hop.minutes)?;
+            let t = utilization * mg_l;
+            tinseth += t;
+            rager += rager_utilization(hop.minutes)? * mg_l / adjustment;
+            breakdown.push((hop, utilization, t));
+        }
+
+        let mut result = CalcResult::new(Measurement::new(tinseth, Unit::Ibu))
+            .with_secondary("Rager IBU", rager.round_dp(1), Unit::Ibu);
+
+        let gravity_units = og.points();
+        if gravity_units > Decimal::ZERO {
+            let ratio = tinseth / gravity_units;
+            result = result.with_secondary("BU:GU", ratio.round_dp(2), Unit::Ratio);
+        } else {
+            result = result.with_warning("OG at or below 1.000; BU:GU ratio not meaningful");
+        }
+
+        for (n, (hop, utilization, ibu)) in breakdown.iter().enumerate() {
+            let (a, g, m) = (hop.alpha, hop.grams, hop.minutes);
+            let label = format!("Addition {} ({}% AA, {} g, {} min)", n + 1, a, g, m);
+            let u = utilization.round_dp(4);
+            let working = format!("{} × {} × {} × 10 / {}", u, a, g, volume);
+            result = result
+                .with_secondary(label, ibu.round_dp(1), Unit::Ibu)
+                .with_step(format!("Addition {}", n + 1), FORMULA, working, *ibu);
+        }
+
+        Ok(result
+            .with_meta("additions", hops.len().to_string())
+            .with_meta("rager_ibu", rager.round_dp(1).to_string())
+            .with_meta("formula", format!("IBU = {}", FORMULA)))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        for key in ["hops", "boil_gravity", "volume"] {
+            if input.get_param(key).is_none() {
+                return Err(Error::MissingInput(format!("{} required", key)));
+            }
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(IbuCalculator);
+
+#[cfg(test)]
+#[path = "ibu_tests.rs"]
+mod tests;
@@ -0,0 +1,69 @@
+use super::*;
+use crate::test_support::{params, secondary};
+
+fn input(hops: &str, gravity: &str) -> CalcInput {
+    params(&[("hops", hops), ("boil_gravity", gravity), ("volume", "20")])
+}
+
+#[test]
+fn test_single_bittering_addition() -> Result<()> {
+    let result = IbuCalculator.calculate(input("5:28:60", "1.050"))?;
+    assert_eq!(result.output.unit, Unit::Ibu);
+    assert_eq!(result.output.value.round_dp(1), Decimal::new(161, 1));
+    assert_eq!(secondary(&result, "Rager IBU"), Some(Decimal::new(216, 1)));
+    assert_eq!(secondary(&result, "BU:GU"), Some(Decimal::new(32, 2)));
+    Ok(())
+}
+
+#[test]
+fn test_breakdown_per_addition() -> Result<()> {
+    let result = IbuCalculator.calculate(input("5:28:60, 8:14:15, 4:20:0", "1.050"))?;
+    let total: Decimal = result
+        .secondary
+        .iter()
+        .filter(|s| s.label.starts_with("Addition"))
+        .map(|s| s.value)
+        .sum();
+    assert!((total - result.output.value).abs() <= Decimal::new(1, 1));
+    assert_eq!(secondary(&result, "Addition 3 (4% AA, 20 g, 0 min)"), Some(Decimal::ZERO));
+    Ok(())
+}
+
+#[test]
+fn test_high_gravity_lowers_bitterness() -> Result<()> {
+    let normal = IbuCalculator.calculate(input("5:28:60", "1.050"))?;
+    let big = IbuCalculator.calculate(input("5:28:60", "1.090"))?;
+    assert!(big.output.value < normal.output.value);
+    assert!(secondary(&big, "Rager IBU") < secondary(&normal, "Rager IBU"));
+    Ok(())
+}
+
+#[test]
+fn test_long_boil_saturates_rager() -> Result<()> {
+    // tanh → 1, so utilization tops out at (18.11 + 13.86) / 100.
+    let result = IbuCalculator.calculate(input("5:28:5000", "1.050"))?;
+    assert_eq!(secondary(&result, "Rager IBU"), Some(Decimal::new(224, 1)));
+    Ok(())
+}
+
+#[test]
+fn test_og_used_for_bu_gu() -> Result<()> {
+    let result = IbuCalculator.calculate(input("5:28:60", "1.040").add_param("og", "1.080"))?;
+    let expected = (result.output.value / Decimal::from(80)).round_dp(2);
+    assert_eq!(secondary(&result, "BU:GU"), Some(expected));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_hop_list() {
+    let result = IbuCalculator.calculate(input("5:28", "1.050"));
+    assert!(matches!(result, Err(Error::Parse(_))));
+}
+
+#[test]
+fn test_missing_volume() {
+    let input = CalcInput::new()
+        .add_param("hops", "5:28:60")
+        .add_param("boil_gravity", "1.050");
+    assert!(matches!(IbuCalculator.calculate(input), Err(Error::MissingInput(_))));
+}
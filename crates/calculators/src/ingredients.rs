@@ -0,0 +1,33 @@
+//! Ingredient database read by calculators: the bundled `ingredients.toml`
+//! unless a front end loads its own with [`set_ingredients`].
+
+use mazerion_config::{parse_ingredients, Ingredients};
+use mazerion_core::{Error, Result};
+use rust_decimal::Decimal;
+use std::sync::{Arc, PoisonError, RwLock};
+
+const BUNDLED: &str = include_str!("../../../ingredients.toml");
+
+static DATABASE: RwLock<Option<Arc<Ingredients>>> = RwLock::new(None);
+
+/// Replace the ingredient database, e.g. with a user's edited `ingredients.toml`.
+pub fn set_ingredients(ingredients: Ingredients) {
+    *DATABASE.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(ingredients));
+}
+
+/// The current database, parsing the bundled one on first use.
+fn database() -> Result<Arc<Ingredients>> {
+    if let Some(ingredients) = DATABASE.read().unwrap_or_else(PoisonError::into_inner).as_ref() {
+        return Ok(Arc::clone(ingredients));
+    }
+    let bundled = Arc::new(parse_ingredients(BUNDLED)?);
+    let mut database = DATABASE.write().unwrap_or_else(PoisonError::into_inner);
+    Ok(Arc::clone(database.get_or_insert(bundled)))
+}
+
+/// Gravity points (PPG) of a sugar such as `honey`.
+pub(crate) fn gravity_points(key: &str) -> Result<Decimal> {
+    database()?
+        .gravity_points(key)
+        .ok_or_else(|| Error::Config(format!("No '{}' sugar in the ingredient database", key)))
+}
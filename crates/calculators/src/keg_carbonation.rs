@@ -0,0 +1,133 @@
+// Keg force-carbonation regulator table and line balancing.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Result,
+    Temperature, Unit,
+};
+use rust_decimal::Decimal;
+
+use crate::co2::{pressure_psi, psi_to_bar, PRESSURE_FORMULA};
+
+/// Beer line inner diameter (mm) and flow resistance (psi per metre).
+const LINES: [(i64, i64); 3] = [(48, 89), (64, 28), (79, 13)];
+
+/// Calculate regulator pressure for kegs, the full setting table and balanced line length.
+#[derive(Default)]
+pub struct KegCarbonationCalculator;
+
+impl KegCarbonationCalculator {
+    pub const ID: &'static str = "keg_carbonation";
+}
+
+impl Calculator for KegCarbonationCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Keg Force Carbonation"
+    }
+
+    fn description(&self) -> &'static str {
+        "Regulator pressure table (0–20 °C, 1.5–4.5 vol) and balanced line length"
+    }
+
+    fn category(&self) -> Category {
+        Category::Brewing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["carbonation", "keg"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["co2", "psi", "force carbonation", "line balancing"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        60
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let temperature: Temperature = input.get_quantity("temp")?;
+        let temp = temperature.as_celsius();
+        let co2 = input.get_decimal("co2")?;
+        if co2 <= Decimal::ZERO || co2 > Decimal::from(6) {
+            return Err(Error::OutOfRange(format!("CO₂ {} volumes outside range 0–6", co2)));
+        }
+
+        let psi = pressure_psi(temperature, co2);
+        if psi < Decimal::ZERO {
+            return Err(Error::Calculation(format!(
+                "{} volumes are already held at {} °C without pressure",
+                co2, temp
+            )));
+        }
+
+        let fahrenheit = temperature.as_fahrenheit();
+        let values = format!("T = {}, V = {}", fahrenheit, co2);
+        let mut result = CalcResult::new(Measurement::new(psi, Unit::Psi))
+            .with_step("T", "°C × 1.8 + 32", format!("{} × 1.8 + 32", temp), fahrenheit)
+            .with_step("Pressure", PRESSURE_FORMULA, values, psi)
+            .with_meta("pressure_bar", psi_to_bar(psi).round_dp(2).to_string());
+
+        if input.get_param("tap_height").is_some() {
+            let height = input.get_decimal("tap_height")?;
+            let diameter = input.get_decimal_or("line_diameter", Decimal::new(48, 1))?;
+            let resistance = LINES
+                .iter()
+                .find(|(d, _)| (Decimal::new(*d, 1) - diameter).abs() <= Decimal::new(3, 1))
+                .map(|(_, r)| Decimal::new(*r, 1))
+                .ok_or_else(|| {
+                    Error::Validation(format!("Unsupported line diameter: {} mm", diameter))
+                })?;
+
+            // Rise costs ~1.6 psi/m and ~1 psi is kept at the faucet for a gentle pour.
+            let available = psi - height * Decimal::new(16, 1) - Decimal::ONE;
+            if available <= Decimal::ZERO {
+                result = result.with_warning("Pressure too low to lift beer to the tap; shorten rise");
+            } else {
+                let length = available / resistance;
+                result = result
+                    .with_secondary("Balanced line length", length.round_dp(2), Unit::Meters)
+                    .with_meta("line_resistance", format!("{} psi/m", resistance));
+            }
+        }
+
+        for step in 0..=10 {
+            let t = Temperature::celsius(Decimal::from(step * 2))?;
+            for tenth in (15..=45).step_by(5) {
+                let vol = Decimal::new(tenth, 1);
+                let cell = pressure_psi(t, vol).max(Decimal::ZERO).round_dp(1);
+                let label = format!("{} °C / {} vol", t.as_celsius(), vol);
+                result = result.with_secondary(label, cell, Unit::Psi);
+            }
+        }
+
+        if psi > Decimal::from(30) {
+            result = result.with_warning("Regulator above 30 psi; check keg and fitting ratings");
+        }
+
+        Ok(result
+            .with_meta("temperature", format!("{} °C", temp))
+            .with_meta("co2_volumes", co2.to_string())
+            .with_meta("formula", "Zahm & Nagel CO₂ solubility fit"))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        for key in ["temp", "co2"] {
+            if input.get_param(key).is_none() {
+                return Err(Error::MissingInput(format!("{} required", key)));
+            }
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(KegCarbonationCalculator);
+
+#[cfg(test)]
+#[path = "keg_carbonation_tests.rs"]
+mod tests;
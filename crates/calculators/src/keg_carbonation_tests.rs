@@ -0,0 +1,74 @@
+use super::*;
+use crate::test_support::params;
+
+fn input(temp: &str, co2: &str) -> CalcInput {
+    params(&[("temp", temp), ("co2", co2)])
+}
+
+#[test]
+fn test_single_point() -> Result<()> {
+    let result = KegCarbonationCalculator.calculate(input("4", "2.5"))?;
+    assert_eq!(result.output.unit, Unit::Psi);
+    assert_eq!(result.output.value.round_dp(1), Decimal::new(119, 1));
+    assert!(result.warnings.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_full_table() -> Result<()> {
+    let result = KegCarbonationCalculator.calculate(input("4", "2.5"))?;
+    // 11 temperatures × 7 CO₂ levels
+    assert_eq!(result.secondary.len(), 77);
+    assert_eq!(result.secondary[0].label, "0 °C / 1.5 vol");
+    // Below zero gauge pressure clamps to 0.
+    assert_eq!(result.secondary[0].value, Decimal::ZERO);
+    let last = &result.secondary[76];
+    assert_eq!(last.label, "20 °C / 4.5 vol");
+    assert_eq!(last.value, Decimal::new(588, 1));
+    Ok(())
+}
+
+#[test]
+fn test_balanced_line_length() -> Result<()> {
+    let calc_input = input("4", "2.5").add_param("tap_height", "1");
+    let result = KegCarbonationCalculator.calculate(calc_input)?;
+    assert_eq!(result.secondary[0].label, "Balanced line length");
+    assert_eq!(result.secondary[0].unit, Unit::Meters);
+    assert_eq!(result.secondary[0].value, Decimal::new(104, 2));
+    Ok(())
+}
+
+#[test]
+fn test_wider_line_is_longer() -> Result<()> {
+    let narrow = input("4", "2.5").add_param("tap_height", "0.5");
+    let wide = narrow.clone().add_param("line_diameter", "6.4");
+    let narrow = KegCarbonationCalculator.calculate(narrow)?;
+    let wide = KegCarbonationCalculator.calculate(wide)?;
+    assert!(wide.secondary[0].value > narrow.secondary[0].value);
+    Ok(())
+}
+
+#[test]
+fn test_unsupported_line_diameter() {
+    let calc_input = input("4", "2.5")
+        .add_param("tap_height", "1")
+        .add_param("line_diameter", "12");
+    assert!(matches!(
+        KegCarbonationCalculator.calculate(calc_input),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_high_pressure_warns() -> Result<()> {
+    let result = KegCarbonationCalculator.calculate(input("18", "4"))?;
+    assert_eq!(result.warnings.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert!(KegCarbonationCalculator.calculate(input("4", "0")).is_err());
+    assert!(KegCarbonationCalculator.calculate(input("0", "1")).is_err());
+    assert!(KegCarbonationCalculator.calculate(CalcInput::new()).is_err());
+}
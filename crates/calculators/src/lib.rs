@@ -1,9 +1,69 @@
 // Calculator implementations for Mazerion.
 
 pub mod abv;
+pub mod alcohol_units;
+pub mod bottle_pressure;
 pub mod brix_to_sg;
+mod co2;
+pub mod colour;
+pub mod deacidification;
+pub mod fining;
+mod fining_agents;
+pub mod freeze_concentration;
+pub mod fruit_addition;
+mod fruits;
+mod grain_bill;
+mod hops;
+mod ingredients;
+pub mod ibu;
+pub mod keg_carbonation;
+pub mod mash;
+pub mod nutrition_facts;
+pub mod pasteurization;
+pub mod residual_sugar;
 pub mod sg_correction;
+pub mod sorbate;
+pub mod sweetness;
+pub mod water_chemistry;
+mod water_salts;
 
 pub use abv::AbvCalculator;
+pub use alcohol_units::AlcoholUnitsCalculator;
+pub use bottle_pressure::BottlePressureCalculator;
 pub use brix_to_sg::BrixToSgCalculator;
+pub use colour::ColourCalculator;
+pub use deacidification::DeacidificationCalculator;
+pub use fining::FiningCalculator;
+pub use freeze_concentration::FreezeConcentrationCalculator;
+pub use fruit_addition::FruitAdditionCalculator;
+pub use ibu::IbuCalculator;
+pub use ingredients::set_ingredients;
+pub use keg_carbonation::KegCarbonationCalculator;
+pub use mash::MashCalculator;
+pub use nutrition_facts::NutritionFactsCalculator;
+pub use pasteurization::PasteurizationCalculator;
+pub use residual_sugar::ResidualSugarCalculator;
 pub use sg_correction::SgCorrectionCalculator;
+pub use sorbate::SorbateCalculator;
+pub use sweetness::SweetnessScale;
+pub use water_chemistry::WaterChemistryCalculator;
+
+/// Link every calculator into the registry and check it for duplicate IDs or aliases.
+pub fn init() -> mazerion_core::Result<()> {
+    mazerion_core::registry::init()
+}
+
+#[cfg(test)]
+mod test_support;
+
+#[cfg(test)]
+#[path = "registry_tests.rs"]
+mod registry_tests;
+
+#[cfg(test)]
+#[path = "pipeline_tests.rs"]
+mod pipeline_tests;
+
+#[cfg(test)]
+#[path = "formula_tests.rs"]
+mod formula_tests;
@@ -0,0 +1,134 @@
+// Grain bill gravity and brewhouse efficiency, with honey and sugar additions.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement,
+    Quantity, Result, Sg, Unit, Volume,
+};
+use rust_decimal::prelude::*;
+
+use crate::grain_bill::{parse_points, to_sg, LB_PER_KG, L_PER_GAL};
+use crate::ingredients::gravity_points;
+
+/// Predict pre- and post-boil gravity from a grain bill, or efficiency from a measured OG.
+#[derive(Default)]
+pub struct MashCalculator;
+
+impl MashCalculator {
+    pub const ID: &'static str = "mash";
+}
+
+impl Calculator for MashCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Mash & Grain Bill"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pre/post-boil gravity from grain PPG and efficiency, or brewhouse efficiency from OG"
+    }
+
+    fn category(&self) -> Category {
+        Category::Brewing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["grain", "gravity", "braggot"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["ppg", "efficiency", "malt"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        10
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let volume = input.get_quantity::<Volume>("volume")?.as_liters();
+        let pre_boil = input.get_quantity_or::<Volume>("pre_boil_volume", volume)?.as_liters();
+        if pre_boil < volume {
+            let message = "Pre-boil volume must be at least the batch volume";
+            return Err(Error::Validation(message.into()));
+        }
+
+        let grains = input.get_param("grains").unwrap_or_default();
+        let grain_potential = parse_points("grains", grains)?;
+        // Honey and sugars dissolve fully, so mash efficiency does not apply to them.
+        let mut sugar_points = match input.get_param("sugars") {
+            Some(raw) => parse_points("sugars", raw)?,
+            None => Decimal::ZERO,
+        };
+        if input.get_param("honey").is_some() {
+            let points = gravity_points("honey")?;
+            sugar_points += input.get_decimal("honey")? * LB_PER_KG * points;
+        }
+        if grain_potential.is_zero() {
+            return Err(Error::Validation("Grain bill has no extract potential".into()));
+        }
+
+        if input.get_param("measured_og").is_some() {
+            let og = input.get_quantity::<Sg>("measured_og")?.base();
+            let measured = (og - Decimal::ONE) * Decimal::from(1000) * volume / L_PER_GAL;
+            let efficiency = (measured - sugar_points) / grain_potential * Decimal::ONE_HUNDRED;
+            let points = [measured, sugar_points, grain_potential].map(|p| p.round_dp(1));
+            let working = format!("({} − {}) / {} × 100", points[0], points[1], points[2]);
+            let formula = "(measured − sugar) / Σ(lb × PPG) × 100";
+            let mut result = CalcResult::new(Measurement::new(efficiency, Unit::Percent))
+                .with_meta("mode", "efficiency")
+                .with_meta("formula", "eff = (measured points − sugar points) / Σ(lb × PPG)")
+                .with_step("Efficiency", formula, working, efficiency);
+            if efficiency <= Decimal::ZERO || efficiency > Decimal::ONE_HUNDRED {
+                result = result.with_warning("Efficiency outside 0–100%; check inputs");
+            }
+            return Ok(result);
+        }
+
+        let efficiency = input.get_decimal_or("efficiency", Decimal::from(75))?;
+        if efficiency <= Decimal::ZERO || efficiency > Decimal::ONE_HUNDRED {
+            return Err(Error::OutOfRange(format!("Efficiency {}% outside 0–100", efficiency)));
+        }
+        let grain_points = grain_potential * efficiency / Decimal::ONE_HUNDRED;
+        let og = to_sg(grain_points + sugar_points, volume);
+        let grain_working = format!("{} × {} / 100", grain_potential.round_dp(1), efficiency);
+        let points = [grain_points, sugar_points, volume / L_PER_GAL].map(|p| p.round_dp(2));
+        let og_working = format!("1 + ({} + {}) / {} / 1000", points[0], points[1], points[2]);
+        let pre_boil_sg = to_sg(grain_points, pre_boil);
+
+        let share = sugar_points / (grain_points + sugar_points) * Decimal::ONE_HUNDRED;
+        let grain_only = to_sg(grain_points, volume);
+        let mut result = CalcResult::new(Measurement::sg(og)?)
+            .with_secondary("Pre-boil gravity", pre_boil_sg.round_dp(4), Unit::SpecificGravity)
+            .with_secondary("Grain-only gravity", grain_only.round_dp(4), Unit::SpecificGravity)
+            .with_secondary("Honey/sugar share of points", share.round_dp(1), Unit::Percent);
+        if !sugar_points.is_zero() {
+            result = result.with_note("Honey and sugars assumed added post-boil at full yield");
+        }
+
+        Ok(result
+            .with_meta("mode", "gravity")
+            .with_meta("efficiency", format!("{}%", efficiency))
+            .with_meta("formula", "SG = 1 + (Σ(lb × PPG) × eff + sugar points) / gal / 1000")
+            .with_step("Grain points", "Σ(lb × PPG) × eff / 100", grain_working, grain_points)
+            .with_step("OG", "1 + (grain + sugar points) / gal / 1000", og_working, og))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        for key in ["grains", "volume"] {
+            if input.get_param(key).is_none() {
+                return Err(Error::MissingInput(format!("{} required", key)));
+            }
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(MashCalculator);
+
+#[cfg(test)]
+#[path = "mash_tests.rs"]
+mod tests;
@@ -0,0 +1,67 @@
+use super::*;
+use crate::test_support::params;
+
+fn with_honey(kg: &str) -> CalcInput {
+    input().add_param("honey", kg)
+}
+
+fn input() -> CalcInput {
+    params(&[("grains", "5:37"), ("volume", "23")])
+}
+
+#[test]
+fn test_grain_bill_og() -> Result<()> {
+    let result = MashCalculator.calculate(input().add_param("pre_boil_volume", "28"))?;
+    assert_eq!(result.output.unit, Unit::SpecificGravity);
+    assert_eq!(result.output.value.round_dp(4), Decimal::new(10503, 4));
+    assert_eq!(result.secondary[0].value, Decimal::new(10414, 4));
+    assert_eq!(result.trace[0].substituted, "407.9 × 75 / 100");
+    Ok(())
+}
+
+#[test]
+fn test_honey_bypasses_efficiency() -> Result<()> {
+    let base = MashCalculator.calculate(input())?;
+    let braggot = MashCalculator.calculate(with_honey("2"))?;
+    // 2 kg honey at 35 PPG (ingredients.toml) in 23 L adds ~25.4 points.
+    let added = (braggot.output.value - base.output.value) * Decimal::from(1000);
+    assert_eq!(added.round_dp(1), Decimal::new(254, 1));
+    assert_eq!(braggot.secondary[1].value, base.output.value.round_dp(4));
+    assert_eq!(braggot.notes.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_honey_points_come_from_ingredient_database() -> Result<()> {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../ingredients.toml");
+    let points = mazerion_config::load_ingredients(path)?.gravity_points("honey");
+    assert_eq!(points, Some(gravity_points("honey")?));
+
+    let honey = MashCalculator.calculate(with_honey("2"))?;
+    let sugars = format!("2:{}", gravity_points("honey")?);
+    let sugars = MashCalculator.calculate(input().add_param("sugars", sugars))?;
+    assert_eq!(honey.output.value, sugars.output.value);
+    Ok(())
+}
+
+#[test]
+fn test_efficiency_from_measured_og() -> Result<()> {
+    let og = MashCalculator.calculate(input().add_param("efficiency", "68"))?;
+    let measured = og.output.value.to_string();
+    let result = MashCalculator.calculate(input().add_param("measured_og", &measured))?;
+    assert_eq!(result.output.unit, Unit::Percent);
+    assert_eq!(result.output.value.round_dp(2), Decimal::from(68));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_efficiency() {
+    let result = MashCalculator.calculate(input().add_param("efficiency", "120"));
+    assert!(matches!(result, Err(Error::OutOfRange(_))));
+}
+
+#[test]
+fn test_pre_boil_smaller_than_batch() {
+    let result = MashCalculator.calculate(input().add_param("pre_boil_volume", "20"));
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
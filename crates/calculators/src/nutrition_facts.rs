@@ -0,0 +1,142 @@
+// Nutrition facts per serving: calories, carbohydrates and alcohol.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement,
+    Quantity, Result, Sg, Unit, Validator,
+};
+use rust_decimal::Decimal;
+
+/// Degrees Plato from SG (ASBC cubic).
+fn plato(sg: Decimal) -> Decimal {
+    Decimal::new(-616868, 3) + Decimal::new(111114, 2) * sg - Decimal::new(630272, 3) * sg * sg
+        + Decimal::new(135997, 3) * sg * sg * sg
+}
+
+/// Per-100 mL alcohol and carbohydrate grams.
+struct Per100Ml {
+    alcohol: Decimal,
+    carbs: Decimal,
+    method: &'static str,
+}
+
+/// ASBC method: real extract and ABW from OG/FG, carbs exclude 0.1 °P of ash.
+fn from_gravity(og: Sg, fg: Sg) -> Result<Per100Ml> {
+    if og < fg {
+        return Err(Error::Validation("OG must be >= FG".into()));
+    }
+    let (oe, ae, fg) = (plato(og.base()), plato(fg.base()), fg.base());
+    let re = Decimal::new(1808, 4) * oe + Decimal::new(8192, 4) * ae;
+    let abw = (oe - re) / (Decimal::new(20665, 4) - Decimal::new(10665, 6) * oe);
+    Ok(Per100Ml {
+        alcohol: abw * fg,
+        carbs: ((re - Decimal::new(1, 1)) * fg).max(Decimal::ZERO),
+        method: "ASBC real extract from OG/FG",
+    })
+}
+
+/// ABV (ethanol 0.789 g/mL) plus residual sugar in g/L.
+fn from_abv(abv: Decimal, residual_sugar: Decimal) -> Result<Per100Ml> {
+    Validator::percent(abv)?;
+    if residual_sugar < Decimal::ZERO {
+        return Err(Error::Validation("Residual sugar cannot be negative".into()));
+    }
+    Ok(Per100Ml {
+        alcohol: abv * Decimal::new(789, 3),
+        carbs: residual_sugar / Decimal::TEN,
+        method: "ABV × 0.789 and residual sugar",
+    })
+}
+
+/// Calculate label nutrition facts for a serving.
+#[derive(Default)]
+pub struct NutritionFactsCalculator;
+
+impl NutritionFactsCalculator {
+    pub const ID: &'static str = "nutrition_facts";
+}
+
+impl Calculator for NutritionFactsCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Nutrition Facts"
+    }
+
+    fn description(&self) -> &'static str {
+        "Calories, carbohydrates and alcohol per serving from OG/FG or ABV and residual sugar"
+    }
+
+    fn category(&self) -> Category {
+        Category::Advanced
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["label", "nutrition"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["calories", "kcal", "carbohydrates"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        40
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let per_100 = if input.get_param("og").is_some() {
+            from_gravity(input.get_quantity("og")?, input.get_quantity("fg")?)?
+        } else {
+            from_abv(input.get_decimal("abv")?, input.get_decimal("residual_sugar")?)?
+        };
+
+        let serving = input.get_decimal_or("serving", Decimal::from(355))?;
+        if serving <= Decimal::ZERO {
+            return Err(Error::Validation("Serving size must be positive".into()));
+        }
+
+        let scale = serving / Decimal::ONE_HUNDRED;
+        let alcohol = per_100.alcohol * scale;
+        let carbs = per_100.carbs * scale;
+        // Atwater factors: 6.9 kcal/g ethanol, 4.0 kcal/g carbohydrate.
+        let kcal = Decimal::new(69, 1) * alcohol + Decimal::from(4) * carbs;
+        let kcal_100 = Decimal::new(69, 1) * per_100.alcohol + Decimal::from(4) * per_100.carbs;
+        let per_serving = |grams: Decimal| format!("{} × {} / 100", grams.round_dp(3), serving);
+        let kcal_working = format!("6.9 × {} + 4.0 × {}", alcohol.round_dp(2), carbs.round_dp(2));
+
+        Ok(CalcResult::new(Measurement::new(kcal, Unit::Kilocalories))
+            .with_meta("serving_size", format!("{} mL", serving.normalize()))
+            .with_meta("calories", format!("{:.0} kcal", kcal.round_dp(0)))
+            .with_meta("carbohydrate", format!("{:.1} g", carbs.round_dp(1)))
+            .with_meta("alcohol", format!("{:.1} g", alcohol.round_dp(1)))
+            .with_meta("calories_per_100ml", format!("{:.0} kcal", kcal_100.round_dp(0)))
+            .with_meta("method", per_100.method)
+            .with_meta("formula", "kcal = 6.9 × alcohol g + 4.0 × carbohydrate g")
+            .with_step("Alcohol", "g/100 mL × serving / 100", per_serving(per_100.alcohol), alcohol)
+            .with_step("Carbs", "g/100 mL × serving / 100", per_serving(per_100.carbs), carbs)
+            .with_step("Calories", "6.9 × alcohol + 4.0 × carbohydrate", kcal_working, kcal))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        let has = |key: &str| input.get_param(key).is_some();
+        if has("og") || has("fg") {
+            if !has("og") || !has("fg") {
+                return Err(Error::MissingInput("og and fg required together".into()));
+            }
+            return Ok(());
+        }
+        if !has("abv") || !has("residual_sugar") {
+            return Err(Error::MissingInput("og/fg or abv/residual_sugar required".into()));
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(NutritionFactsCalculator);
+
+#[cfg(test)]
+#[path = "nutrition_facts_tests.rs"]
+mod tests;
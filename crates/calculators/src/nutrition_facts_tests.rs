@@ -0,0 +1,60 @@
+use super::*;
+use crate::test_support::meta;
+
+#[test]
+fn test_beer_from_gravity() -> Result<()> {
+    let input = CalcInput::new().add_param("og", "1.050").add_param("fg", "1.010");
+    let result = NutritionFactsCalculator.calculate(input)?;
+    assert_eq!(result.output.unit, Unit::Kilocalories);
+    assert_eq!(result.output.value.round_dp(0), Decimal::from(164));
+    assert_eq!(meta(&result, "carbohydrate"), Some("15.2 g"));
+    assert_eq!(meta(&result, "alcohol"), Some("14.9 g"));
+    assert_eq!(meta(&result, "serving_size"), Some("355 mL"));
+    assert_eq!(result.trace.len(), 3);
+    assert!(result.trace[2].substituted.starts_with("6.9 × 14.9"));
+    Ok(())
+}
+
+#[test]
+fn test_custom_serving() -> Result<()> {
+    let input = CalcInput::new()
+        .add_param("og", "1.100")
+        .add_param("fg", "1.000")
+        .add_param("serving", "150");
+    let result = NutritionFactsCalculator.calculate(input)?;
+    assert_eq!(result.output.value.round_dp(0), Decimal::from(136));
+    assert_eq!(meta(&result, "serving_size"), Some("150 mL"));
+    Ok(())
+}
+
+#[test]
+fn test_from_abv_and_residual_sugar() -> Result<()> {
+    let input = CalcInput::new()
+        .add_param("abv", "12")
+        .add_param("residual_sugar", "30")
+        .add_param("serving", "100");
+    let result = NutritionFactsCalculator.calculate(input)?;
+    // 9.468 g alcohol × 6.9 + 3 g sugar × 4
+    assert_eq!(result.output.value.round_dp(2), Decimal::new(7733, 2));
+    assert_eq!(meta(&result, "carbohydrate"), Some("3.0 g"));
+    Ok(())
+}
+
+#[test]
+fn test_fg_above_og_rejected() {
+    let input = CalcInput::new().add_param("og", "1.000").add_param("fg", "1.010");
+    assert!(matches!(
+        NutritionFactsCalculator.calculate(input),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_missing_inputs() {
+    let only_og = CalcInput::new().add_param("og", "1.050");
+    assert!(matches!(
+        NutritionFactsCalculator.calculate(only_og),
+        Err(Error::MissingInput(_))
+    ));
+    assert!(NutritionFactsCalculator.calculate(CalcInput::new()).is_err());
+}
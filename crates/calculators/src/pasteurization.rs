@@ -0,0 +1,150 @@
+// Heat pasteurization units (PU) from a bath profile, or hold time for a target PU.
+
+use mazerion_core::{
+    math, register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement,
+    Result, Temperature, Unit,
+};
+use rust_decimal::prelude::*;
+
+use crate::co2::{find_bottle, henry_pressure_psi};
+
+const FORMULA: &str = "minutes × 10^((T − 60) / Z)";
+
+/// PU accumulated per minute at `temp`: 10^((T − 60 °C) / Z).
+fn pu_per_minute(temp: Temperature, z: Decimal) -> Result<Decimal> {
+    math::pow(Decimal::TEN, (temp.as_celsius() - Decimal::from(60)) / z)
+}
+
+/// Parse a `temp:minutes` comma-separated bath profile.
+fn parse_profile(raw: &str) -> Result<Vec<(Temperature, Decimal)>> {
+    raw.split(',')
+        .map(|segment| {
+            let (temp, minutes) = segment
+                .split_once(':')
+                .ok_or_else(|| Error::Parse(format!("Expected temp:minutes, got '{}'", segment)))?;
+            let parse = |s: &str| {
+                Decimal::from_str(s.trim())
+                    .map_err(|_| Error::Parse(format!("Invalid profile value: {}", s)))
+            };
+            let (temp, minutes) = (Temperature::celsius(parse(temp)?)?, parse(minutes)?);
+            if minutes < Decimal::ZERO {
+                return Err(Error::Validation("Profile minutes cannot be negative".into()));
+            }
+            Ok((temp, minutes))
+        })
+        .collect()
+}
+
+/// Calculate pasteurization units or the hold time needed to reach a target.
+#[derive(Default)]
+pub struct PasteurizationCalculator;
+
+impl PasteurizationCalculator {
+    pub const ID: &'static str = "pasteurization";
+}
+
+impl Calculator for PasteurizationCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Heat Pasteurization"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pasteurization units from a bath temperature/time profile, or hold time for a target PU"
+    }
+
+    fn category(&self) -> Category {
+        Category::Finishing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["stabilizing", "heat"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["pu", "bottle pasteurization"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        40
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let z = input.get_decimal_or("z_value", Decimal::from(7))?;
+        if z <= Decimal::ZERO {
+            return Err(Error::Validation("Z-value must be positive".into()));
+        }
+
+        let (mut result, max_temp) = if let Some(raw) = input.get_param("profile") {
+            let profile = parse_profile(raw)?;
+            let max_temp = profile.iter().map(|(t, _)| *t).max();
+            let max_temp = max_temp.ok_or_else(|| Error::Parse("Empty profile".into()))?;
+            let (total, count) = (Decimal::ZERO, profile.len().to_string());
+            let mut result = CalcResult::new(Measurement::new(total, Unit::PasteurizationUnits))
+                .with_meta("segments", count);
+            for (n, (temp, minutes)) in profile.iter().enumerate() {
+                let pu = pu_per_minute(*temp, z)? * minutes;
+                let working = format!("{} × 10^(({} − 60) / {})", minutes, temp.as_celsius(), z);
+                result.output.value += pu;
+                result = result.with_step(format!("Segment {}", n + 1), FORMULA, working, pu);
+            }
+            (result, max_temp)
+        } else {
+            let temp: Temperature = input.get_quantity("temp")?;
+            let target = input.get_decimal("target_pu")?;
+            if target <= Decimal::ZERO {
+                return Err(Error::Validation("Target PU must be positive".into()));
+            }
+            let minutes = target / pu_per_minute(temp, z)?;
+            let working = format!("{} / 10^(({} − 60) / {})", target, temp.as_celsius(), z);
+            let result = CalcResult::new(Measurement::new(minutes, Unit::Minutes))
+                .with_meta("target_pu", target.to_string())
+                .with_step("Hold time", "target PU / 10^((T − 60) / Z)", working, minutes);
+            (result, temp)
+        };
+
+        if max_temp.as_celsius() < Decimal::from(55) {
+            result = result.with_warning("Below ~55 °C pasteurization is negligibly slow");
+        }
+
+        if input.get_param("co2").is_some() {
+            let co2 = input.get_decimal("co2")?;
+            let bottle = find_bottle(input.get_param("bottle").unwrap_or("standard"))?;
+            let psi = henry_pressure_psi(max_temp, co2)?.max(Decimal::ZERO);
+            if psi > Decimal::from(bottle.max_psi) {
+                result = result.with_warning(format!(
+                    "DANGER: ~{} psi at {} °C exceeds the {} psi limit of a {}",
+                    psi.round_dp(0),
+                    max_temp.as_celsius(),
+                    bottle.max_psi,
+                    bottle.name.to_lowercase()
+                ));
+            }
+            result = result.with_meta("estimated_pressure", format!("{} psi", psi.round_dp(1)));
+        }
+
+        Ok(result
+            .with_meta("z_value", format!("{} °C", z))
+            .with_meta("reference", "1 PU = 1 minute at 60 °C")
+            .with_meta("formula", format!("PU = Σ {}", FORMULA)))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        let has = |key: &str| input.get_param(key).is_some();
+        if !(has("profile") || has("temp") && has("target_pu")) {
+            return Err(Error::MissingInput("profile, or temp and target_pu, required".into()));
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(PasteurizationCalculator);
+
+#[cfg(test)]
+#[path = "pasteurization_tests.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+use super::*;
+use crate::test_support::meta;
+
+fn profile(raw: &str) -> CalcInput {
+    CalcInput::new().add_param("profile", raw)
+}
+
+#[test]
+fn test_reference_temperature() -> Result<()> {
+    let result = PasteurizationCalculator.calculate(profile("60:10"))?;
+    assert_eq!(result.output.unit, Unit::PasteurizationUnits);
+    assert_eq!(result.output.value.round_dp(4), Decimal::from(10));
+    Ok(())
+}
+
+#[test]
+fn test_multi_segment_profile() -> Result<()> {
+    // One Z-value above reference is ten times faster.
+    let result = PasteurizationCalculator.calculate(profile("60:5, 67:5, 53:10"))?;
+    assert_eq!(result.output.value.round_dp(2), Decimal::from(56));
+    assert_eq!(result.trace[1].substituted, "5 × 10^((67 − 60) / 7)");
+    Ok(())
+}
+
+#[test]
+fn test_hold_time_for_target() -> Result<()> {
+    let input = CalcInput::new()
+        .add_param("temp", "67")
+        .add_param("target_pu", "50");
+    let result = PasteurizationCalculator.calculate(input)?;
+    assert_eq!(result.output.unit, Unit::Minutes);
+    assert_eq!(result.output.value.round_dp(4), Decimal::from(5));
+    Ok(())
+}
+
+#[test]
+fn test_carbonated_product_warns() -> Result<()> {
+    let input = profile("65:10").add_param("co2", "2.5");
+    let result = PasteurizationCalculator.calculate(input)?;
+    assert!(result.warnings.iter().any(|w| w.starts_with("DANGER")));
+    // Henry's law at 65 °C, well beyond the serving-range Zahm & Nagel fit.
+    assert_eq!(meta(&result, "estimated_pressure"), Some("110.2 psi"));
+
+    let champagne = profile("60:10").add_param("co2", "2.0").add_param("bottle", "champagne");
+    let result = PasteurizationCalculator.calculate(champagne)?;
+    assert!(result.warnings.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_low_temperature_warns() -> Result<()> {
+    let result = PasteurizationCalculator.calculate(profile("50:30"))?;
+    assert_eq!(result.warnings.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_bad_profile() {
+    assert!(matches!(
+        PasteurizationCalculator.calculate(profile("60-10")),
+        Err(Error::Parse(_))
+    ));
+    assert!(PasteurizationCalculator.calculate(profile("60:-1")).is_err());
+    assert!(PasteurizationCalculator.calculate(profile("150:1")).is_err());
+}
+
+#[test]
+fn test_missing_inputs() {
+    let only_temp = CalcInput::new().add_param("temp", "65");
+    assert!(matches!(
+        PasteurizationCalculator.calculate(only_temp),
+        Err(Error::MissingInput(_))
+    ));
+}
@@ -0,0 +1,30 @@
+use mazerion_core::{Error, Pipeline, PipelineStep, Result};
+use rust_decimal::Decimal;
+
+fn example() -> Result<Pipeline> {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../pipelines/brix_to_drinks.toml");
+    mazerion_config::load_pipeline(path)
+}
+
+#[test]
+fn test_example_pipeline_runs() -> Result<()> {
+    crate::init()?;
+    let pipeline = example()?;
+    assert_eq!(pipeline.steps.len(), 3);
+    let run = pipeline.run()?;
+    assert_eq!(run.get("og").map(|r| r.output.value), Some(Decimal::new(1096, 3)));
+    assert_eq!(run.get("abv").map(|r| r.output.value), Some(Decimal::new(126, 1)));
+    // 750 mL × 12.6% × 0.789 g/mL ≈ 74.6 g of ethanol, in UK units of 8 g first.
+    let drinks = run.output().and_then(|r| r.secondary.iter().find(|s| s.label.contains("8 g")));
+    assert_eq!(drinks.map(|s| s.value), Some(Decimal::new(93, 1)));
+    assert!(run.warnings.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_step_failure_stops_pipeline() -> Result<()> {
+    crate::init()?;
+    let pipeline = example()?.then(PipelineStep::new("abv").named("bad").input("og", "abv.fg"));
+    assert!(matches!(pipeline.run(), Err(Error::Calculation(_))));
+    Ok(())
+}
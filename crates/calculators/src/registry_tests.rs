@@ -0,0 +1,51 @@
+use mazerion_core::{
+    find_calculator, get_all_calculators, list_by_category, search_calculators, CalculatorHandle,
+    Category,
+};
+
+fn ids(calcs: &[CalculatorHandle]) -> Vec<String> {
+    calcs.iter().map(|c| c.id().to_string()).collect()
+}
+
+fn top(query: &str) -> Option<String> {
+    ids(&search_calculators(query)).into_iter().next()
+}
+
+#[test]
+fn test_every_category_populated() {
+    for category in Category::ALL {
+        assert!(!list_by_category(category).is_empty(), "{category} is empty");
+    }
+}
+
+#[test]
+fn test_category_sort_order() {
+    let basic = ids(&list_by_category(Category::Basic));
+    assert_eq!(basic[..3], ["abv", "brix_to_sg", "alcohol_units"]);
+}
+
+#[test]
+fn test_search_registered_calculators() {
+    assert_eq!(top("tinseth").as_deref(), Some("ibu"));
+    assert_eq!(top("bentonite").as_deref(), Some("fining"));
+    assert_eq!(top("gypsum").as_deref(), Some("water_chemistry"));
+    assert!(ids(&search_calculators("stabilizing")).iter().any(|id| id == "sorbate"));
+}
+
+#[test]
+fn test_init_has_no_duplicates() {
+    assert!(crate::init().is_ok());
+    assert!(get_all_calculators().len() >= 18);
+}
+
+#[test]
+fn test_find_returns_shared_instance() {
+    let (first, second) = (find_calculator("abv"), find_calculator("abv"));
+    assert!(first.zip(second).is_some_and(|(a, b)| std::ptr::addr_eq(a, b)));
+}
+
+#[test]
+fn test_alias_lookup() {
+    assert!(find_calculator("color").is_some_and(|c| c.id() == "colour"));
+    assert!(find_calculator("no_such_calculator").is_none());
+}
@@ -0,0 +1,132 @@
+// Residual sugar and sweetness classification from final gravity.
+
+use mazerion_core::{
+    register_calculator, Brix, CalcInput, CalcResult, Calculator, Category, Error, Measurement,
+    Quantity, Result, Sg, Unit, Validator,
+};
+use rust_decimal::Decimal;
+
+use crate::sweetness::SweetnessScale;
+
+/// SG of a sucrose solution from Brix (inverse of Brix = 261.3 × (1 − 1/SG)).
+fn brix_to_sg(brix: Decimal) -> Decimal {
+    let k = Decimal::new(2613, 1);
+    k / (k - brix)
+}
+
+/// Refractometer wort correction factor: true Brix = reading / 1.04.
+const WCF: Decimal = Decimal::from_parts(104, 0, 0, false, 2);
+
+/// Terrill cubic FG from original and current refractometer Brix.
+fn refractometer_fg(original: Decimal, current: Decimal) -> Decimal {
+    let (ob, fb) = (original / WCF, current / WCF);
+    Decimal::ONE - Decimal::new(44993, 7) * ob + Decimal::new(11774, 6) * fb
+        + Decimal::new(27581, 8) * ob * ob
+        - Decimal::new(12717, 7) * fb * fb
+        - Decimal::new(72800, 10) * ob * ob * ob
+        + Decimal::new(63293, 9) * fb * fb * fb
+}
+
+/// Estimate residual sugar from FG corrected for alcohol, and classify sweetness.
+#[derive(Default)]
+pub struct ResidualSugarCalculator;
+
+impl ResidualSugarCalculator {
+    pub const ID: &'static str = "residual_sugar";
+}
+
+impl Calculator for ResidualSugarCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Residual Sugar"
+    }
+
+    fn description(&self) -> &'static str {
+        "Estimate residual sugar (g/L) and sweetness from FG, Brix or refractometer and ABV"
+    }
+
+    fn category(&self) -> Category {
+        Category::Advanced
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["sweetness", "sugar"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["rs", "terrill", "refractometer"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        20
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+        let scale = SweetnessScale::from_input(&input)?;
+
+        let (fg, abv, source) = if input.get_param("final_brix").is_some() {
+            let original = input.get_quantity::<Brix>("original_brix")?.base();
+            let current = input.get_quantity::<Brix>("final_brix")?.base();
+            let fg = Sg::new(refractometer_fg(original, current))?;
+            let og_abv = (brix_to_sg(original / WCF) - fg.base()) * Decimal::new(13125, 2);
+            (fg, input.get_decimal_or("abv", og_abv)?, "refractometer")
+        } else if input.get_param("brix").is_some() {
+            let brix = input.get_quantity::<Brix>("brix")?.base();
+            (Sg::new(brix_to_sg(brix))?, input.get_decimal("abv")?, "brix")
+        } else {
+            (input.get_quantity::<Sg>("fg")?, input.get_decimal("abv")?, "fg")
+        };
+        let fg = fg.base();
+        Validator::percent(abv)?;
+
+        // Ethanol lowers SG ≈ 0.00133 per % ABV; non-sugar extract adds ≈ 0.006.
+        let sugar_sg = fg + abv * Decimal::new(133, 5) - Decimal::new(6, 3);
+        let brix = Decimal::new(2613, 1) * (Decimal::ONE - Decimal::ONE / sugar_sg);
+        let brix = brix.max(Decimal::ZERO);
+        let residual = brix * sugar_sg * Decimal::TEN;
+        let (fg4, sg4, brix2) = (fg.round_dp(4), sugar_sg.round_dp(4), brix.round_dp(2));
+
+        Ok(CalcResult::new(Measurement::new(residual, Unit::GramsPerLiter))
+            .with_step(
+                "Sugar SG",
+                "FG + 0.00133 × ABV − 0.006",
+                format!("{} + 0.00133 × {} − 0.006", fg4, abv.round_dp(2)),
+                sugar_sg,
+            )
+            .with_step("Brix", "261.3 × (1 − 1 / SG)", format!("261.3 × (1 − 1 / {})", sg4), brix)
+            .with_step("Sugar", "Brix × SG × 10", format!("{} × {} × 10", brix2, sg4), residual)
+            .with_meta("sweetness", scale.classify(residual))
+            .with_meta("residual_sugar_wv", format!("{}%", (residual / Decimal::TEN).round_dp(2)))
+            .with_meta("fg", fg.round_dp(4).to_string())
+            .with_meta("abv", abv.round_dp(2).to_string())
+            .with_meta("source", source)
+            .with_meta("formula", "sugar SG = FG + 0.00133 × ABV − 0.006; g/L = Brix × SG × 10"))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        let has = |key: &str| input.get_param(key).is_some();
+        if has("final_brix") && !has("original_brix") {
+            return Err(Error::MissingInput("original_brix required".into()));
+        }
+        if has("final_brix") {
+            return Ok(());
+        }
+        if !has("fg") && !has("brix") {
+            return Err(Error::MissingInput("fg, brix or final_brix required".into()));
+        }
+        if !has("abv") {
+            return Err(Error::MissingInput("abv required".into()));
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(ResidualSugarCalculator);
+
+#[cfg(test)]
+#[path = "residual_sugar_tests.rs"]
+mod tests;
@@ -0,0 +1,79 @@
+use super::*;
+use crate::test_support::{meta, params};
+
+fn input(fg: &str, abv: &str) -> CalcInput {
+    params(&[("fg", fg), ("abv", abv)])
+}
+
+#[test]
+fn test_sweet_mead() -> Result<()> {
+    let result = ResidualSugarCalculator.calculate(input("1.010", "12"))?;
+    assert_eq!(result.output.unit, Unit::GramsPerLiter);
+    assert_eq!(result.output.value.round_dp(1), Decimal::new(522, 1));
+    assert_eq!(meta(&result, "sweetness"), Some("sweet"));
+    assert_eq!(meta(&result, "residual_sugar_wv"), Some("5.22%"));
+    Ok(())
+}
+
+#[test]
+fn test_bone_dry_clamps_to_zero() -> Result<()> {
+    let result = ResidualSugarCalculator.calculate(input("0.985", "14"))?;
+    assert_eq!(result.output.value, Decimal::ZERO);
+    assert_eq!(meta(&result, "sweetness"), Some("dry"));
+    Ok(())
+}
+
+#[test]
+fn test_custom_scale() -> Result<()> {
+    let calc_input = input("1.010", "12").add_param("semi_sweet_max", "60");
+    let result = ResidualSugarCalculator.calculate(calc_input)?;
+    assert_eq!(meta(&result, "sweetness"), Some("semi-sweet"));
+    Ok(())
+}
+
+#[test]
+fn test_brix_input() -> Result<()> {
+    let calc_input = CalcInput::new().add_param("brix", "2.5").add_param("abv", "12");
+    let result = ResidualSugarCalculator.calculate(calc_input)?;
+    assert_eq!(meta(&result, "source"), Some("brix"));
+    assert!(result.output.value > Decimal::from(45));
+    Ok(())
+}
+
+#[test]
+fn test_refractometer_input_derives_abv() -> Result<()> {
+    let calc_input = CalcInput::new()
+        .add_param("original_brix", "24")
+        .add_param("final_brix", "10");
+    let result = ResidualSugarCalculator.calculate(calc_input)?;
+    assert_eq!(meta(&result, "fg"), Some("1.0055"));
+    // OG from 24 / 1.04 = 23.08 °Bx true extract, not the raw reading.
+    assert_eq!(meta(&result, "abv"), Some("11.99"));
+    Ok(())
+}
+
+#[test]
+fn test_scale_classify() {
+    let scale = SweetnessScale::default();
+    assert_eq!(scale.classify(Decimal::from(5)), "dry");
+    assert_eq!(scale.classify(Decimal::from(15)), "medium");
+    assert_eq!(scale.classify(Decimal::from(30)), "semi-sweet");
+    assert_eq!(scale.classify(Decimal::from(80)), "sweet");
+}
+
+#[test]
+fn test_non_increasing_scale_rejected() {
+    let calc_input = input("1.010", "12").add_param("dry_max", "20");
+    assert!(matches!(
+        ResidualSugarCalculator.calculate(calc_input),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_missing_inputs() {
+    let result = ResidualSugarCalculator.calculate(CalcInput::new().add_param("fg", "1.000"));
+    assert!(matches!(result, Err(Error::MissingInput(_))));
+    let result = ResidualSugarCalculator.calculate(CalcInput::new().add_param("final_brix", "8"));
+    assert!(matches!(result, Err(Error::MissingInput(_))));
+}
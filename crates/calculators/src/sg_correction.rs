@@ -1,12 +1,12 @@
 // Temperature correction for specific gravity readings.
 
 use mazerion_core::{
-    register_calculator, CalcInput, CalcResult, Calculator, Measurement, Result, Unit,
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Quantity, Result, Sg,
+    Temperature,
 };
 use rust_decimal::Decimal;
-use std::str::FromStr;
 
-/// Correct SG reading for temperature (calibrated at 20°C).
+/// Correct SG reading for temperature (calibrated at 20°C), read in °C or °F.
 #[derive(Default)]
 pub struct SgCorrectionCalculator;
 
@@ -27,12 +27,29 @@ impl Calculator for SgCorrectionCalculator {
         "Correct specific gravity reading for temperature (calibrated at 20°C)"
     }
 
-    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
-        let sg_meas = input.get_measurement(Unit::SpecificGravity)?;
-        let temp_meas = input.get_measurement(Unit::Celsius)?;
+    fn category(&self) -> Category {
+        Category::Advanced
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["gravity", "temperature"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["hydrometer", "calibration"]
+    }
 
-        let sg = sg_meas.value;
-        let temp = temp_meas.value;
+    fn sort_order(&self) -> u32 {
+        10
+    }
+
+    fn version(&self) -> u32 {
+        2
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        let sg = input.get_measured::<Sg>()?.base();
+        let temp = input.get_measured::<Temperature>()?.as_celsius();
 
         let cal_temp = Decimal::from(20);
         let correction_factor = Decimal::new(13, 5); // 0.00013
@@ -40,8 +57,9 @@ impl Calculator for SgCorrectionCalculator {
         let correction = correction_factor * temp_diff;
 
         let corrected_sg = sg + correction;
+        let (temp2, correction6) = (temp.round_dp(2), correction.round_dp(6));
 
-        let mut result = CalcResult::new(Measurement::sg(corrected_sg)?);
+        let mut result = CalcResult::new(Sg::new(corrected_sg)?.measurement());
 
         if (temp - cal_temp).abs() > Decimal::from(10) {
             result = result.with_warning("Large temperature deviation from calibration (20°C)");
@@ -49,18 +67,34 @@ impl Calculator for SgCorrectionCalculator {
 
         result = result
             .with_meta("measured_sg", sg.to_string())
-            .with_meta("temperature", format!("{} °C", temp))
-            .with_meta("correction", correction.to_string())
-            .with_meta("calibration", "20°C");
+            .with_meta("temperature", format!("{} °C", temp2))
+            .with_meta("correction", correction6.to_string())
+            .with_meta("calibration", "20°C")
+            .with_step(
+                "Correction",
+                "0.00013 × (T − 20 °C)",
+                format!("0.00013 × ({} − 20)", temp2),
+                correction,
+            )
+            .with_step(
+                "Corrected SG",
+                "SG + correction",
+                format!("{} + {}", sg, correction6),
+                corrected_sg,
+            );
 
         Ok(result)
     }
 
     fn validate(&self, input: &CalcInput) -> Result<()> {
-        input.get_measurement(Unit::SpecificGravity)?;
-        input.get_measurement(Unit::Celsius)?;
+        input.get_measured::<Sg>()?;
+        input.get_measured::<Temperature>()?;
         Ok(())
     }
 }
 
 register_calculator!(SgCorrectionCalculator);
+
+#[cfg(test)]
+#[path = "sg_correction_tests.rs"]
+mod tests;
@@ -1 +1,36 @@
-// sg_correction tests - not implemented yet
+use super::*;
+use mazerion_core::{Error, Measurement, Unit};
+use rust_decimal::Decimal;
+
+fn reading(sg: i64, temp: i64, unit: Unit) -> CalcInput {
+    CalcInput::new()
+        .add_measurement(Measurement::new(Decimal::new(sg, 3), Unit::SpecificGravity))
+        .add_measurement(Measurement::new(Decimal::from(temp), unit))
+}
+
+#[test]
+fn test_celsius_and_fahrenheit_agree() -> Result<()> {
+    let celsius = SgCorrectionCalculator.calculate(reading(1050, 30, Unit::Celsius))?;
+    assert_eq!(celsius.output.value, Decimal::new(10513, 4));
+    let fahrenheit = SgCorrectionCalculator.calculate(reading(1050, 86, Unit::Fahrenheit))?;
+    assert_eq!(fahrenheit.output, celsius.output);
+    Ok(())
+}
+
+#[test]
+fn test_fahrenheit_is_not_rounded_before_correcting() -> Result<()> {
+    // 70 °F is 21.111… °C; rounding to 21.11 first would give 1.0501443.
+    let result = SgCorrectionCalculator.calculate(reading(1050, 70, Unit::Fahrenheit))?;
+    assert_eq!(result.output.value.round_dp(9), Decimal::new(1050144444, 9));
+    assert_eq!(result.trace[0].substituted, "0.00013 × (21.11 − 20)");
+    assert_eq!(SgCorrectionCalculator.version(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_rejects_out_of_range_reading() {
+    let hot = SgCorrectionCalculator.calculate(reading(1050, 250, Unit::Fahrenheit));
+    assert!(matches!(hot, Err(Error::OutOfRange(_))));
+    let missing = CalcInput::new().add_measurement(Measurement::new(Decimal::ONE, Unit::Brix));
+    assert!(matches!(SgCorrectionCalculator.validate(&missing), Err(Error::MissingInput(_))));
+}
@@ -0,0 +1,133 @@
+// Potassium sorbate dosing based on ABV and pH.
+
+use mazerion_core::{
+    math, register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Ph,
+    Quantity, Result, Unit, Validator, Volume,
+};
+use rust_decimal::Decimal;
+
+/// pKa of sorbic acid.
+const SORBIC_PKA: Decimal = Decimal::from_parts(476, 0, 0, false, 2);
+/// First pKa of sulfurous acid.
+const SO2_PKA: Decimal = Decimal::from_parts(181, 0, 0, false, 2);
+/// Molecular SO₂ (mg/L) that protects against spoilage.
+const MOLECULAR_SO2: Decimal = Decimal::from_parts(8, 0, 0, false, 1);
+
+/// Undissociated sorbic acid fraction: 1 / (1 + 10^(pH − 4.76)).
+fn undissociated(ph: Decimal) -> Result<Decimal> {
+    Ok(Decimal::ONE / (Decimal::ONE + math::pow(Decimal::TEN, ph - SORBIC_PKA)?))
+}
+
+/// Free SO₂ (mg/L) giving 0.8 mg/L molecular SO₂: 0.8 × (1 + 10^(pH − 1.81)).
+fn free_so2(ph: Decimal) -> Result<Decimal> {
+    Ok(MOLECULAR_SO2 * (Decimal::ONE + math::pow(Decimal::TEN, ph - SO2_PKA)?))
+}
+
+/// Calculate potassium sorbate additions for stabilization.
+#[derive(Default)]
+pub struct SorbateCalculator;
+
+impl SorbateCalculator {
+    pub const ID: &'static str = "sorbate";
+}
+
+impl Calculator for SorbateCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Potassium Sorbate"
+    }
+
+    fn description(&self) -> &'static str {
+        "Calculate potassium sorbate for stabilization from volume, ABV and pH"
+    }
+
+    fn category(&self) -> Category {
+        Category::Finishing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["stabilizing", "preservative"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["potassium sorbate", "k-meta", "so2"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        20
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let volume = input.get_quantity::<Volume>("volume")?.as_liters();
+        let abv = input.get_decimal("abv")?;
+        let ph: Ph = input.get_quantity("ph")?;
+        Validator::percent(abv)?;
+
+        // Sorbic acid needed at pH 3.5: 150 mg/L at 10% ABV, 25 mg/L less per % ABV,
+        // never below 50 mg/L; scaled by the undissociated fraction at the actual pH.
+        let base = (Decimal::from(400) - Decimal::from(25) * abv).max(Decimal::from(50));
+        let reference = undissociated(Decimal::new(35, 1))?;
+        let actual = undissociated(ph.base())?;
+        let sorbic_mg_l = base * reference / actual;
+        let sorbate_mg_l = sorbic_mg_l / Decimal::new(74, 2); // 74% sorbic acid by mass
+        let grams = sorbate_mg_l * volume / Decimal::from(1000);
+        let (u_ref, u_ph) = (reference.round_dp(4), actual.round_dp(4));
+        let sorbic_working = format!("max(400 − 25 × {}, 50) × {} / {}", abv, u_ref, u_ph);
+        let sorbate_working = format!("{} / 0.74 × {} / 1000", sorbic_mg_l.round_dp(1), volume);
+
+        let mut result = CalcResult::new(Measurement::new(grams, Unit::Grams));
+
+        if sorbic_mg_l > Decimal::from(150) {
+            result = result.with_warning(format!(
+                "{} mg/L sorbic acid exceeds the ~150 mg/L taste threshold",
+                sorbic_mg_l.round_dp(0)
+            ));
+        }
+        if ph.base() > Decimal::new(38, 1) {
+            result = result.with_warning("pH > 3.8 makes sorbate unreliable; lower pH first");
+        }
+
+        let free_so2 = free_so2(ph.base())?;
+        let kmeta = free_so2 * volume / Decimal::new(576, 0); // 57.6% SO₂ yield, mg→g
+        result = result.with_note(format!(
+            "Add {} g potassium metabisulfite ({} ppm free SO₂ at pH {}) with the sorbate \
+             to prevent geranium off-flavours",
+            kmeta.round_dp(2),
+            free_so2.round_dp(0),
+            ph.base()
+        ));
+
+        Ok(result
+            .with_meta("sorbic_acid", format!("{} mg/L", sorbic_mg_l.round_dp(0)))
+            .with_meta("potassium_sorbate", format!("{} mg/L", sorbate_mg_l.round_dp(0)))
+            .with_meta("formula", "sorbic mg/L = max(400 − 25 × ABV, 50) × pH factor")
+            .with_meta("undissociated", "f(pH) = 1 / (1 + 10^(pH − 4.76))")
+            .with_step(
+                "Sorbic acid",
+                "max(400 − 25 × ABV, 50) × f(3.5) / f(pH)",
+                sorbic_working,
+                sorbic_mg_l,
+            )
+            .with_step("Sorbate", "sorbic mg/L / 0.74 × litres / 1000", sorbate_working, grams))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        for key in ["volume", "abv", "ph"] {
+            if input.get_param(key).is_none() {
+                return Err(Error::MissingInput(format!("{} required", key)));
+            }
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(SorbateCalculator);
+
+#[cfg(test)]
+#[path = "sorbate_tests.rs"]
+mod tests;
@@ -0,0 +1,72 @@
+use super::*;
+use crate::test_support::params;
+
+fn input(volume: &str, abv: &str, ph: &str) -> CalcInput {
+    params(&[("volume", volume), ("abv", abv), ("ph", ph)])
+}
+
+#[test]
+fn test_typical_mead() -> Result<()> {
+    // 12% ABV at pH 3.5 needs 100 mg/L sorbic acid → 135 mg/L sorbate.
+    let result = SorbateCalculator.calculate(input("20", "12", "3.5"))?;
+    assert_eq!(result.output.unit, Unit::Grams);
+    assert_eq!(result.output.value.round_dp(2), Decimal::new(270, 2));
+    assert!(result.warnings.is_empty());
+    assert_eq!(result.notes.len(), 1);
+    assert_eq!(result.trace[0].substituted, "max(400 − 25 × 12, 50) × 0.9479 / 0.9479");
+    Ok(())
+}
+
+#[test]
+fn test_higher_ph_needs_more() -> Result<()> {
+    let low = SorbateCalculator.calculate(input("20", "12", "3.2"))?;
+    let high = SorbateCalculator.calculate(input("20", "12", "3.7"))?;
+    assert!(high.output.value > low.output.value);
+    Ok(())
+}
+
+#[test]
+fn test_low_abv_exceeds_taste_threshold() -> Result<()> {
+    let result = SorbateCalculator.calculate(input("20", "6", "3.5"))?;
+    assert_eq!(result.warnings.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_high_abv_floor() -> Result<()> {
+    let result = SorbateCalculator.calculate(input("1", "18", "3.5"))?;
+    // Floor of 50 mg/L sorbic acid.
+    assert_eq!(result.output.value.round_dp(4), Decimal::new(676, 4));
+    Ok(())
+}
+
+#[test]
+fn test_sulfite_note_tracks_ph() -> Result<()> {
+    let result = SorbateCalculator.calculate(input("10", "12", "3.4"))?;
+    assert!(result.notes[0].contains("32 ppm"));
+    Ok(())
+}
+
+#[test]
+fn test_high_ph_warns() -> Result<()> {
+    let result = SorbateCalculator.calculate(input("10", "12", "3.9"))?;
+    assert!(result.warnings.iter().any(|w| w.contains("pH")));
+    Ok(())
+}
+
+#[test]
+fn test_dissociation_formulas() -> Result<()> {
+    // At the pKa half the sorbic acid is undissociated.
+    assert_eq!(undissociated(Decimal::new(476, 2))?, Decimal::new(5, 1));
+    assert_eq!(undissociated(Decimal::new(35, 1))?.round_dp(4), Decimal::new(9479, 4));
+    // 0.8 × (1 + 10^1.64) ≈ 35.7 mg/L at pH 3.45.
+    assert_eq!(free_so2(Decimal::new(345, 2))?.round_dp(1), Decimal::new(357, 1));
+    assert_eq!(free_so2(Decimal::new(181, 2))?, Decimal::new(16, 1));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_volume() {
+    let result = SorbateCalculator.calculate(input("0", "12", "3.5"));
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
@@ -0,0 +1,51 @@
+// Sweetness terms for residual sugar levels.
+
+use mazerion_core::{CalcInput, Error, Result};
+use rust_decimal::Decimal;
+
+/// Upper residual sugar bounds (g/L) for each sweetness term; above `semi_sweet_max` is sweet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SweetnessScale {
+    pub dry_max: Decimal,
+    pub medium_max: Decimal,
+    pub semi_sweet_max: Decimal,
+}
+
+impl Default for SweetnessScale {
+    fn default() -> Self {
+        Self {
+            dry_max: Decimal::from(9),
+            medium_max: Decimal::from(18),
+            semi_sweet_max: Decimal::from(45),
+        }
+    }
+}
+
+impl SweetnessScale {
+    /// Build a scale from `dry_max`/`medium_max`/`semi_sweet_max` params, defaulting the rest.
+    pub fn from_input(input: &CalcInput) -> Result<Self> {
+        let default = Self::default();
+        let scale = Self {
+            dry_max: input.get_decimal_or("dry_max", default.dry_max)?,
+            medium_max: input.get_decimal_or("medium_max", default.medium_max)?,
+            semi_sweet_max: input.get_decimal_or("semi_sweet_max", default.semi_sweet_max)?,
+        };
+        if scale.dry_max >= scale.medium_max || scale.medium_max >= scale.semi_sweet_max {
+            return Err(Error::Validation("Sweetness bounds must be increasing".into()));
+        }
+        Ok(scale)
+    }
+
+    /// Sweetness term for a residual sugar level in g/L.
+    pub fn classify(&self, residual_sugar: Decimal) -> &'static str {
+        if residual_sugar <= self.dry_max {
+            "dry"
+        } else if residual_sugar <= self.medium_max {
+            "medium"
+        } else if residual_sugar <= self.semi_sweet_max {
+            "semi-sweet"
+        } else {
+            "sweet"
+        }
+    }
+}
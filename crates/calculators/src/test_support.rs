@@ -0,0 +1,19 @@
+//! Helpers shared by the calculator unit tests.
+
+use mazerion_core::{CalcInput, CalcResult};
+use rust_decimal::Decimal;
+
+/// Input with each `(key, value)` pair as a parameter.
+pub(crate) fn params(pairs: &[(&str, &str)]) -> CalcInput {
+    pairs.iter().fold(CalcInput::new(), |input, (key, value)| input.add_param(*key, *value))
+}
+
+/// Metadata entry stored under `key`.
+pub(crate) fn meta<'a>(result: &'a CalcResult, key: &str) -> Option<&'a str> {
+    result.metadata.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+}
+
+/// Secondary value reported with `label`.
+pub(crate) fn secondary(result: &CalcResult, label: &str) -> Option<Decimal> {
+    result.secondary.iter().find(|s| s.label == label).map(|s| s.value)
+}
@@ -0,0 +1,148 @@
+// Brewing water chemistry: salt additions, ion profile and residual alkalinity.
+
+use mazerion_core::{
+    register_calculator, CalcInput, CalcResult, Calculator, Category, Error, Measurement, Result,
+    Unit, Volume,
+};
+use rust_decimal::Decimal;
+
+use crate::water_salts::{apply, residual_alkalinity, solve, Profile, IONS, SALTS};
+
+const RA_FORMULA: &str = "HCO3 × 50/61 − (Ca/1.4 + Mg/1.7)";
+
+/// Report the ion profile from salt additions, or solve salts for a target profile.
+#[derive(Default)]
+pub struct WaterChemistryCalculator;
+
+impl WaterChemistryCalculator {
+    pub const ID: &'static str = "water_chemistry";
+}
+
+impl Calculator for WaterChemistryCalculator {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Water Chemistry"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ion profile, SO4:Cl ratio and residual alkalinity from salts, or salts for a target"
+    }
+
+    fn category(&self) -> Category {
+        Category::Brewing
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        vec!["water", "salts"]
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        &["gypsum", "calcium chloride", "alkalinity", "ions"]
+    }
+
+    fn sort_order(&self) -> u32 {
+        30
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let volume = input.get_quantity::<Volume>("volume")?.as_liters();
+
+        // Source water defaults to RO (all ions zero).
+        let mut source: Profile = [Decimal::ZERO; 6];
+        let mut targets = [None; 6];
+        for (i, (key, _)) in IONS.iter().enumerate() {
+            source[i] = input.get_decimal_or(key, Decimal::ZERO)?;
+            let target_key = format!("target_{}", key);
+            if input.get_param(&target_key).is_some() {
+                targets[i] = Some(input.get_decimal(&target_key)?);
+            }
+        }
+        if source.iter().chain(targets.iter().flatten()).any(|v| *v < Decimal::ZERO) {
+            return Err(Error::Validation("Ion concentrations cannot be negative".into()));
+        }
+
+        let solving = targets.iter().any(Option::is_some);
+        let doses = if solving {
+            solve(&source, &targets)
+        } else {
+            let mut doses = [Decimal::ZERO; 5];
+            for (dose, salt) in doses.iter_mut().zip(&SALTS) {
+                let grams = input.get_decimal_or(salt.key, Decimal::ZERO)?;
+                if grams < Decimal::ZERO {
+                    return Err(Error::Validation(format!("{} cannot be negative", salt.key)));
+                }
+                *dose = grams / volume;
+            }
+            doses
+        };
+        let profile = apply(&source, &doses);
+        let ra = residual_alkalinity(&profile);
+        let [ca, mg, .., hco3] = profile.map(|ion| ion.round_dp(1));
+        let working = format!("{} × 50/61 − ({}/1.4 + {}/1.7)", hco3, ca, mg);
+
+        let mut result = if solving {
+            let total: Decimal = doses.iter().map(|d| d * volume).sum();
+            let mut result = CalcResult::new(Measurement::new(total, Unit::Grams));
+            for (salt, dose) in SALTS.iter().zip(&doses) {
+                result = result.with_secondary(salt.name, (dose * volume).round_dp(2), Unit::Grams);
+            }
+            for (i, target) in targets.iter().enumerate() {
+                if let Some(target) = target
+                    && source[i] > *target
+                {
+                    result = result.with_note(format!(
+                        "{} already exceeds the target; dilute with RO water",
+                        IONS[i].1
+                    ));
+                }
+            }
+            result
+                .with_secondary("Residual alkalinity", ra.round_dp(0), Unit::Ppm)
+                .with_meta("mode", "solve")
+        } else {
+            CalcResult::new(Measurement::new(ra, Unit::Ppm)).with_meta("mode", "additions")
+        };
+
+        for ((_, label), value) in IONS.iter().zip(&profile) {
+            result = result.with_secondary(*label, value.round_dp(0), Unit::Ppm);
+        }
+        if profile[3].is_zero() {
+            result = result.with_meta("so4_cl_ratio", "n/a (no chloride)");
+        } else {
+            let ratio = profile[4] / profile[3];
+            result = result
+                .with_secondary("SO4:Cl", ratio.round_dp(2), Unit::Ratio)
+                .with_meta("balance", match ratio {
+                    r if r < Decimal::new(8, 1) => "malty",
+                    r if r > Decimal::new(15, 1) => "bitter",
+                    _ => "balanced",
+                });
+        }
+        if doses[4] > Decimal::ZERO {
+            result = result.with_warning("Chalk dissolves poorly without CO₂ or acid");
+        }
+
+        Ok(result
+            .with_meta("residual_alkalinity", format!("{} ppm as CaCO₃", ra.round_dp(0)))
+            .with_meta("formula", format!("RA = {}", RA_FORMULA))
+            .with_step("Residual alkalinity", RA_FORMULA, working, ra))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        if input.get_param("volume").is_none() {
+            return Err(Error::MissingInput("volume required".into()));
+        }
+        Ok(())
+    }
+}
+
+register_calculator!(WaterChemistryCalculator);
+
+#[cfg(test)]
+#[path = "water_chemistry_tests.rs"]
+mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+use crate::test_support::{meta, secondary};
+
+fn ro(volume: &str) -> CalcInput {
+    CalcInput::new().add_param("volume", volume)
+}
+
+#[test]
+fn test_gypsum_in_ro() -> Result<()> {
+    let result = WaterChemistryCalculator.calculate(ro("20").add_param("gypsum", "20"))?;
+    assert_eq!(result.output.unit, Unit::Ppm);
+    assert_eq!(result.output.value.round_dp(0), Decimal::from(-166));
+    assert_eq!(secondary(&result, "Ca"), Some(Decimal::from(233)));
+    assert_eq!(secondary(&result, "SO4"), Some(Decimal::from(558)));
+    assert_eq!(secondary(&result, "SO4:Cl"), None);
+    assert_eq!(meta(&result, "mode"), Some("additions"));
+    Ok(())
+}
+
+#[test]
+fn test_sulfate_chloride_ratio() -> Result<()> {
+    let input = ro("20").add_param("gypsum", "10").add_param("calcium_chloride", "10");
+    let result = WaterChemistryCalculator.calculate(input)?;
+    assert_eq!(secondary(&result, "Ca"), Some(Decimal::from(253)));
+    assert_eq!(secondary(&result, "SO4:Cl"), Some(Decimal::new(116, 2)));
+    Ok(())
+}
+
+#[test]
+fn test_source_water_alkalinity() -> Result<()> {
+    let input = ro("10").add_param("hco3", "122").add_param("ca", "14");
+    let result = WaterChemistryCalculator.calculate(input)?;
+    // 122 × 50/61 = 100 ppm alkalinity, minus 14/1.4 = 10.
+    assert_eq!(result.output.value, Decimal::from(90));
+    assert_eq!(result.trace[0].substituted, "122 × 50/61 − (14/1.4 + 0/1.7)");
+    Ok(())
+}
+
+#[test]
+fn test_solve_single_target() -> Result<()> {
+    let result = WaterChemistryCalculator.calculate(ro("20").add_param("target_cl", "100"))?;
+    assert_eq!(result.output.unit, Unit::Grams);
+    assert_eq!(secondary(&result, SALTS[1].name), Some(Decimal::new(415, 2)));
+    assert_eq!(secondary(&result, "Cl"), Some(Decimal::from(100)));
+    assert_eq!(meta(&result, "mode"), Some("solve"));
+    Ok(())
+}
+
+#[test]
+fn test_solve_towards_profile() -> Result<()> {
+    let input = ro("20")
+        .add_param("target_ca", "100")
+        .add_param("target_so4", "150")
+        .add_param("target_cl", "100");
+    let result = WaterChemistryCalculator.calculate(input)?;
+    for (ion, target) in [("Ca", 100), ("SO4", 150), ("Cl", 100)] {
+        let value = secondary(&result, ion).unwrap_or_default();
+        assert!((value - Decimal::from(target)).abs() <= Decimal::from(5), "{ion}: {value}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_target_below_source_adds_note() -> Result<()> {
+    let input = ro("20").add_param("na", "50").add_param("target_na", "20");
+    let result = WaterChemistryCalculator.calculate(input)?;
+    assert_eq!(result.output.value, Decimal::ZERO);
+    assert_eq!(result.notes.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_negative_salt() {
+    let result = WaterChemistryCalculator.calculate(ro("20").add_param("epsom", "-1"));
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
@@ -0,0 +1,79 @@
+// Brewing salt contributions, residual alkalinity and a target-profile solver.
+
+use rust_decimal::Decimal;
+
+/// Ion order used by every profile array: (param key, label).
+pub(crate) const IONS: [(&str, &str); 6] = [
+    ("ca", "Ca"),
+    ("mg", "Mg"),
+    ("na", "Na"),
+    ("cl", "Cl"),
+    ("so4", "SO4"),
+    ("hco3", "HCO3"),
+];
+
+/// Ion concentrations in mg/L, ordered as [`IONS`].
+pub(crate) type Profile = [Decimal; 6];
+
+/// Brewing salt with the ppm (×10) it adds per gram dissolved in one litre.
+pub(crate) struct Salt {
+    pub(crate) key: &'static str,
+    pub(crate) name: &'static str,
+    ppm: [i64; 6],
+}
+
+#[rustfmt::skip]
+pub(crate) const SALTS: [Salt; 5] = [
+    Salt { key: "gypsum", name: "Gypsum (CaSO₄·2H₂O)", ppm: [2328, 0, 0, 0, 5579, 0] },
+    Salt { key: "calcium_chloride", name: "Calcium chloride (CaCl₂·2H₂O)", ppm: [2726, 0, 0, 4823, 0, 0] },
+    Salt { key: "epsom", name: "Epsom salt (MgSO₄·7H₂O)", ppm: [0, 986, 0, 0, 3897, 0] },
+    Salt { key: "baking_soda", name: "Baking soda (NaHCO₃)", ppm: [0, 0, 2737, 0, 0, 7263] },
+    Salt { key: "chalk", name: "Chalk (CaCO₃)", ppm: [4004, 0, 0, 0, 0, 12192] },
+];
+
+impl Salt {
+    /// mg/L of `ion` added per g/L of this salt.
+    fn ppm(&self, ion: usize) -> Decimal {
+        Decimal::new(self.ppm[ion], 1)
+    }
+}
+
+/// Profile after dissolving `doses` (g/L, ordered as [`SALTS`]) into `source`.
+pub(crate) fn apply(source: &Profile, doses: &[Decimal; 5]) -> Profile {
+    let mut profile = *source;
+    for (ion, value) in profile.iter_mut().enumerate() {
+        for (salt, dose) in SALTS.iter().zip(doses) {
+            *value += salt.ppm(ion) * dose;
+        }
+    }
+    profile
+}
+
+/// Kolbach residual alkalinity as ppm CaCO₃: alkalinity − (Ca/1.4 + Mg/1.7).
+pub(crate) fn residual_alkalinity(profile: &Profile) -> Decimal {
+    let alkalinity = profile[5] * Decimal::from(50) / Decimal::from(61);
+    alkalinity - profile[0] / Decimal::new(14, 1) - profile[1] / Decimal::new(17, 1)
+}
+
+/// Non-negative least-squares salt doses (g/L) moving `source` towards the given targets.
+///
+/// Projected coordinate descent over the salts; ions without a target are ignored.
+pub(crate) fn solve(source: &Profile, targets: &[Option<Decimal>; 6]) -> [Decimal; 5] {
+    let mut doses = [Decimal::ZERO; 5];
+    for _ in 0..200 {
+        for (j, salt) in SALTS.iter().enumerate() {
+            let current = apply(source, &doses);
+            let (mut num, mut den) = (Decimal::ZERO, Decimal::ZERO);
+            for (ion, target) in targets.iter().enumerate() {
+                if let Some(target) = target {
+                    num += salt.ppm(ion) * (target - current[ion]);
+                    den += salt.ppm(ion) * salt.ppm(ion);
+                }
+            }
+            if !den.is_zero() {
+                doses[j] = (doses[j] + num / den).max(Decimal::ZERO).round_dp(6);
+            }
+        }
+    }
+    doses
+}
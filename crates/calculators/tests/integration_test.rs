@@ -0,0 +1,57 @@
+use mazerion_core::{
+    find_calculator, get_all_calculators, CalcInput, CalcResult, Error, Measurement, Result, Unit,
+};
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+
+fn run(id: &str, input: CalcInput) -> Result<CalcResult> {
+    let calc = find_calculator(id).ok_or(Error::Config(format!("{} not registered", id)))?;
+    calc.calculate(input)
+}
+
+#[test]
+fn test_all_calculators_registered() -> Result<()> {
+    mazerion_calculators::init()?;
+    let calculators = get_all_calculators();
+    assert!(calculators.len() >= 18, "only {} calculators", calculators.len());
+
+    let mut names = HashSet::new();
+    for calc in calculators.iter() {
+        for name in std::iter::once(calc.id()).chain(calc.aliases().iter().copied()) {
+            assert!(names.insert(name), "'{}' registered twice", name);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_find_calculator_by_id() -> Result<()> {
+    mazerion_calculators::init()?;
+    for id in ["abv", "brix_to_sg", "sg_correction", "mash", "sorbate", "water_chemistry"] {
+        assert!(find_calculator(id).is_some_and(|c| c.id() == id));
+    }
+    assert!(find_calculator("nonexistent").is_none());
+    Ok(())
+}
+
+#[test]
+fn test_brix_then_abv_workflow() -> Result<()> {
+    mazerion_calculators::init()?;
+    let brix = Measurement::new(Decimal::from(30), Unit::Brix);
+    let og = run("brix_to_sg", CalcInput::new().add_measurement(brix))?.output.value;
+    assert_eq!(og, Decimal::new(112, 2));
+
+    let input = CalcInput::new().add_param("og", og.to_string()).add_param("fg", "1.000");
+    let abv = run("abv", input)?;
+    assert!((abv.output.value - Decimal::new(1575, 2)).abs() < Decimal::new(1, 2));
+    Ok(())
+}
+
+#[test]
+fn test_empty_input_is_rejected() -> Result<()> {
+    mazerion_calculators::init()?;
+    for calc in get_all_calculators().iter() {
+        assert!(calc.calculate(CalcInput::new()).is_err(), "{} accepted empty input", calc.id());
+    }
+    Ok(())
+}
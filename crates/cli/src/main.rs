@@ -1,30 +1,101 @@
-use mazerion_core::get_all_calculators;
+use mazerion_core::{find_handle, list_by_category, search_calculators, CalcInput, Category};
+use mazerion_config::FormulaWatcher;
 use std::env;
+use std::path::Path;
 
 fn main() {
-    mazerion_calculators::init();
+    if let Err(e) = mazerion_calculators::init() {
+        eprintln!("Registry error: {}", e);
+        std::process::exit(1);
+    }
+    // The GUI and TUI keep polling this so formula edits apply while they run.
+    let mut formulas = Path::new("formulas.toml")
+        .exists()
+        .then(|| FormulaWatcher::new("formulas.toml"));
+    if let Some(watcher) = formulas.as_mut()
+        && let Err(e) = watcher.poll()
+    {
+        eprintln!("Formula error: {}", e);
+    }
+    // Calculators fall back to the bundled ingredient database.
+    if Path::new("ingredients.toml").exists() {
+        match mazerion_config::load_ingredients("ingredients.toml") {
+            Ok(ingredients) => mazerion_calculators::set_ingredients(ingredients),
+            Err(e) => eprintln!("Ingredient error: {}", e),
+        }
+    }
 
     let args: Vec<String> = env::args().collect();
     let mode = args.get(1).map(String::as_str);
+    let explain = args.iter().any(|arg| arg == "--explain");
 
     match mode {
         Some("gui") => {
-            if let Err(e) = mazerion_gui::run() {
+            if let Err(e) = mazerion_gui::run(formulas) {
                 eprintln!("GUI error: {}", e);
                 std::process::exit(1);
             }
         }
         Some("tui") => {
-            if let Err(e) = mazerion_tui::run() {
+            if let Err(e) = mazerion_tui::run(formulas) {
                 eprintln!("TUI error: {}", e);
                 std::process::exit(1);
             }
         }
         Some("list") => {
             println!("Available Calculators:");
-            for calc in get_all_calculators() {
-                println!("  {} - {}", calc.id(), calc.name());
-                println!("    {}", calc.description());
+            for category in Category::ALL {
+                println!("\n{}:", category);
+                for calc in list_by_category(category) {
+                    println!("  {} - {}", calc.id(), calc.name());
+                    println!("    {}", calc.description());
+                }
+            }
+        }
+        Some("search") => {
+            let query = args[2..].join(" ");
+            for calc in search_calculators(&query) {
+                println!("  {} - {} [{}]", calc.id(), calc.name(), calc.category());
+            }
+        }
+        Some("pipeline") => {
+            let run = args.get(2).map(mazerion_config::load_pipeline).map(|p| p?.run());
+            match run {
+                Some(Ok(run)) => {
+                    for (step, result) in &run.steps {
+                        if explain {
+                            println!("== {} ==\n{}", step, result.explain());
+                        } else {
+                            println!("  {}: {}", step, result.output);
+                        }
+                    }
+                    for warning in &run.warnings {
+                        println!("  Warning - {}", warning);
+                    }
+                }
+                Some(Err(e)) => {
+                    eprintln!("Pipeline error: {}", e);
+                    std::process::exit(1);
+                }
+                None => eprintln!("Usage: mazerion pipeline <file.toml>"),
+            }
+        }
+        Some("calc") => {
+            let Some(calc) = args.get(2).and_then(|id| find_handle(id)) else {
+                eprintln!("Usage: mazerion calc <id> key=value... [--explain]");
+                std::process::exit(1);
+            };
+            let input = args[3..]
+                .iter()
+                .filter_map(|arg| arg.split_once('='))
+                .fold(CalcInput::new(), |input, (k, v)| input.add_param(k, v));
+            match calc.calculate(input) {
+                Ok(result) if explain => print!("{}", result.explain()),
+                Ok(result) => println!("{}", result.output),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
             }
         }
         _ => {
@@ -32,7 +103,11 @@ fn main() {
             println!("\nUsage:");
             println!("  mazerion gui   - Launch GUI (recommended)");
             println!("  mazerion tui   - Launch TUI");
-            println!("  mazerion list  - List all calculators");
+            println!("  mazerion list  - List all calculators by category");
+            println!("  mazerion search <terms> - Find calculators by name, tag or keyword");
+            println!("  mazerion calc <id> key=value... - Run one calculator");
+            println!("  mazerion pipeline <file.toml> - Run a calculation pipeline");
+            println!("  --explain      - With calc or pipeline, show the working step by step");
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+//! User formula calculators loaded from TOML, with hot reload.
+
+use crate::FileWatcher;
+use mazerion_core::{
+    register_user_calculators, Calculator, Error, FormulaCalculator, FormulaDefinition, Result,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Formula file contents: a list of `[[calculators]]` tables.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormulaFile {
+    #[serde(default)]
+    pub calculators: Vec<FormulaDefinition>,
+}
+
+/// Parse formula definitions from TOML text.
+pub fn parse_formulas(content: &str) -> Result<Vec<FormulaDefinition>> {
+    toml::from_str::<FormulaFile>(content)
+        .map(|file| file.calculators)
+        .map_err(|e| Error::Config(format!("Failed to parse formulas: {}", e)))
+}
+
+/// Load formula definitions from TOML file.
+pub fn load_formulas(path: impl AsRef<Path>) -> Result<Vec<FormulaDefinition>> {
+    let content = fs::read_to_string(path.as_ref())
+        .map_err(|e| Error::Config(format!("Failed to read formulas: {}", e)))?;
+    parse_formulas(&content)
+}
+
+/// Build every formula and register them in place of earlier user calculators.
+///
+/// Nothing is replaced unless every formula builds and no ID clashes.
+pub fn register_formulas(definitions: Vec<FormulaDefinition>) -> Result<usize> {
+    let calculators = definitions
+        .into_iter()
+        .map(|d| FormulaCalculator::new(d).map(|c| Box::new(c) as Box<dyn Calculator>))
+        .collect::<Result<Vec<_>>>()?;
+    let count = calculators.len();
+    register_user_calculators(calculators)?;
+    Ok(count)
+}
+
+/// Re-registers the calculators in a formula file whenever it changes.
+pub struct FormulaWatcher {
+    watcher: FileWatcher,
+}
+
+impl FormulaWatcher {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self { watcher: FileWatcher::new(path) }
+    }
+
+    /// Reload if the file changed since the last poll (always on the first).
+    ///
+    /// Returns how many calculators were registered, or `None` when unchanged.
+    /// An invalid file is reported once and keeps the previous calculators
+    /// until it is saved again.
+    pub fn poll(&mut self) -> Result<Option<usize>> {
+        if !self.watcher.check_changed()? {
+            return Ok(None);
+        }
+        register_formulas(parse_formulas(&self.watcher.load()?)?).map(Some)
+    }
+}
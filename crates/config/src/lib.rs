@@ -1,11 +1,19 @@
 //! Configuration with hot-reload support.
 
-use mazerion_core::{Error, Result};
+use mazerion_core::{Error, Pipeline, Result};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+mod formulas;
+
+pub use formulas::{
+    load_formulas, parse_formulas, register_formulas, FormulaFile, FormulaWatcher,
+};
+
 /// Application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -43,9 +51,27 @@ pub struct Ingredient {
     pub sugar_content: Option<f64>,
 }
 
+/// Fermentable sugar with its extract potential in gravity points per lb per US gal (PPG).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sugar {
+    pub name: String,
+    pub gravity_points: Decimal,
+    pub fermentability: Decimal,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ingredients {
+    #[serde(default)]
     pub items: Vec<Ingredient>,
+    #[serde(default)]
+    pub sugars: BTreeMap<String, Sugar>,
+}
+
+impl Ingredients {
+    /// Gravity points (PPG) for a sugar key such as `honey`.
+    pub fn gravity_points(&self, key: &str) -> Option<Decimal> {
+        self.sugars.get(key).map(|sugar| sugar.gravity_points)
+    }
 }
 
 /// File watcher for hot-reload.
@@ -74,8 +100,7 @@ impl FileWatcher {
             .map_err(|e| Error::Io(format!("Failed to get mtime: {}", e)))?;
         let size = metadata.len();
 
-        let changed = self.last_modified.map_or(true, |last| last != modified)
-            || self.last_size.map_or(true, |last| last != size);
+        let changed = self.last_modified != Some(modified) || self.last_size != Some(size);
 
         if changed {
             self.last_modified = Some(modified);
@@ -99,10 +124,22 @@ pub fn load_config(path: impl AsRef<Path>) -> Result<Config> {
     toml::from_str(&content).map_err(|e| Error::Config(format!("Failed to parse config: {}", e)))
 }
 
+/// Parse ingredients from TOML text.
+pub fn parse_ingredients(content: &str) -> Result<Ingredients> {
+    toml::from_str(content)
+        .map_err(|e| Error::Config(format!("Failed to parse ingredients: {}", e)))
+}
+
 /// Load ingredients from TOML file.
 pub fn load_ingredients(path: impl AsRef<Path>) -> Result<Ingredients> {
     let content = fs::read_to_string(path.as_ref())
         .map_err(|e| Error::Config(format!("Failed to read ingredients: {}", e)))?;
-    toml::from_str(&content)
-        .map_err(|e| Error::Config(format!("Failed to parse ingredients: {}", e)))
+    parse_ingredients(&content)
+}
+
+/// Load a calculation pipeline from TOML file.
+pub fn load_pipeline(path: impl AsRef<Path>) -> Result<Pipeline> {
+    let content = fs::read_to_string(path.as_ref())
+        .map_err(|e| Error::Config(format!("Failed to read pipeline: {}", e)))?;
+    toml::from_str(&content).map_err(|e| Error::Config(format!("Failed to parse pipeline: {}", e)))
 }
@@ -13,12 +13,12 @@ mod tests {
 
     #[test]
     fn test_add_measurement() {
-        if let Ok(val) = Decimal::from_str("1.050") {
-            if let Ok(sg_meas) = Measurement::sg(val) {
-                let input = CalcInput::new().add_measurement(sg_meas);
-                assert_eq!(input.measurements.len(), 1);
-                assert_eq!(input.measurements[0].unit, Unit::SpecificGravity);
-            }
+        if let Ok(val) = Decimal::from_str("1.050")
+            && let Ok(sg_meas) = Measurement::sg(val)
+        {
+            let input = CalcInput::new().add_measurement(sg_meas);
+            assert_eq!(input.measurements.len(), 1);
+            assert_eq!(input.measurements[0].unit, Unit::SpecificGravity);
         }
     }
 
@@ -89,15 +89,44 @@ mod tests {
 
     #[test]
     fn test_chaining() {
-        if let Ok(val) = Decimal::from_str("1.050") {
-            if let Ok(sg_meas) = Measurement::sg(val) {
-                let input = CalcInput::new()
-                    .add_param("test", "value")
-                    .add_measurement(sg_meas);
-                
-                assert_eq!(input.params.len(), 1);
-                assert_eq!(input.measurements.len(), 1);
-            }
+        if let Ok(val) = Decimal::from_str("1.050")
+            && let Ok(sg_meas) = Measurement::sg(val)
+        {
+            let input = CalcInput::new()
+                .add_param("test", "value")
+                .add_measurement(sg_meas);
+            
+            assert_eq!(input.params.len(), 1);
+            assert_eq!(input.measurements.len(), 1);
         }
     }
+
+    #[test]
+    fn test_get_decimal() {
+        let input = CalcInput::new().add_param("og", " 1.050 ").add_param("bad", "abc");
+
+        assert_eq!(input.get_decimal("og").ok(), Decimal::from_str("1.050").ok());
+        assert!(matches!(input.get_decimal("bad"), Err(crate::Error::Parse(_))));
+        assert!(matches!(input.get_decimal("missing"), Err(crate::Error::MissingInput(_))));
+    }
+
+    #[test]
+    fn test_get_decimal_or() {
+        let input = CalcInput::new().add_param("temp", "15");
+
+        assert_eq!(input.get_decimal_or("temp", Decimal::from(20)).ok(), Some(Decimal::from(15)));
+        assert_eq!(input.get_decimal_or("missing", Decimal::from(20)).ok(), Some(Decimal::from(20)));
+    }
+
+    #[test]
+    fn test_set_param_replaces() {
+        let input = CalcInput::new()
+            .add_param("og", "1.050")
+            .set_param("og", "1.100")
+            .set_param("fg", "1.000");
+
+        assert_eq!(input.params.len(), 2);
+        assert_eq!(input.get_param("og"), Some("1.100"));
+        assert_eq!(input.get_param("fg"), Some("1.000"));
+    }
 }
@@ -28,18 +28,18 @@ mod tests {
 
     #[test]
     fn test_with_meta() {
-        if let Ok(val) = Decimal::from_str("1.050") {
-            if let Ok(sg_meas) = Measurement::sg(val) {
-                let result = CalcResult::new(sg_meas)
-                    .with_meta("og", "1.100")
-                    .with_meta("fg", "1.010");
-                
-                assert_eq!(result.metadata.len(), 2);
-                assert_eq!(result.metadata[0].0, "og");
-                assert_eq!(result.metadata[0].1, "1.100");
-                assert_eq!(result.metadata[1].0, "fg");
-                assert_eq!(result.metadata[1].1, "1.010");
-            }
+        if let Ok(val) = Decimal::from_str("1.050")
+            && let Ok(sg_meas) = Measurement::sg(val)
+        {
+            let result = CalcResult::new(sg_meas)
+                .with_meta("og", "1.100")
+                .with_meta("fg", "1.010");
+            
+            assert_eq!(result.metadata.len(), 2);
+            assert_eq!(result.metadata[0].0, "og");
+            assert_eq!(result.metadata[0].1, "1.100");
+            assert_eq!(result.metadata[1].0, "fg");
+            assert_eq!(result.metadata[1].1, "1.010");
         }
     }
 
@@ -68,4 +68,42 @@ mod tests {
         assert!(result.warnings.is_empty());
         assert!(result.metadata.is_empty());
     }
+
+    #[test]
+    fn test_with_note() {
+        let result = CalcResult::new(Measurement::new(Decimal::from(5), Unit::Grams))
+            .with_note("Add sulfite too")
+            .with_warning("Check pH");
+
+        assert_eq!(result.notes, vec!["Add sulfite too".to_string()]);
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_with_secondary() {
+        let result = CalcResult::new(Measurement::new(Decimal::from(12), Unit::Psi))
+            .with_secondary("4 °C / 2.5 vol", Decimal::from(12), Unit::Psi)
+            .with_secondary("Line length", Decimal::from(3), Unit::Meters);
+
+        assert_eq!(result.secondary.len(), 2);
+        assert_eq!(result.secondary[0].label, "4 °C / 2.5 vol");
+        assert_eq!(result.secondary[1].unit, Unit::Meters);
+    }
+
+    #[test]
+    fn test_trace_and_explain() {
+        let abv = Decimal::new(118125, 4);
+        let result = CalcResult::new(Measurement::new(abv, Unit::Abv))
+            .with_step("Gravity drop", "OG − FG", "1.100 − 1.010", Decimal::new(90, 3))
+            .with_step("ABV", "drop × 131.25", "0.090 × 131.25", abv)
+            .with_warning("Check FG");
+
+        assert_eq!(result.trace.len(), 2);
+        assert_eq!(result.trace[1].substituted, "0.090 × 131.25");
+        let text = result.explain();
+        let first = "1. Gravity drop = OG − FG\n   = 1.100 − 1.010\n   = 0.09\n";
+        assert!(text.starts_with(first));
+        assert!(text.contains("Result: 11.8125"));
+        assert!(text.ends_with("Warning: Check FG\n"));
+    }
 }
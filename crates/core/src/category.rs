@@ -0,0 +1,34 @@
+//! Calculator categories used by front ends to group calculators.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Calculator category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Category {
+    Basic,
+    Advanced,
+    Brewing,
+    Finishing,
+}
+
+impl Category {
+    /// All categories in display order.
+    pub const ALL: [Category; 4] = [Self::Basic, Self::Advanced, Self::Brewing, Self::Finishing];
+
+    /// Display label.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Basic => "Basic",
+            Self::Advanced => "Advanced",
+            Self::Brewing => "Brewing",
+            Self::Finishing => "Finishing",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
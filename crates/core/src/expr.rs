@@ -0,0 +1,137 @@
+//! Safe arithmetic expressions with `Decimal` semantics for user-defined formulas.
+//!
+//! From lowest to highest precedence: one comparison (`< <= > >= == !=`,
+//! giving 1 or 0), `+ -`, `* /`, unary `-`, then right-associative `^`.
+//! Operands are numbers, variables, parentheses and the functions in [`Func`].
+//! Every operation is checked, so overflow, division by zero and arguments
+//! outside a function's domain are errors rather than panics.
+
+use crate::expr_func::checked;
+use crate::math;
+use crate::{Error, Result};
+use rust_decimal::Decimal;
+
+pub use crate::expr_func::Func;
+
+/// Binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+/// Operators, two-character forms first so `<=` is not read as `<`.
+pub(crate) const OPERATORS: [(&str, BinaryOp); 11] = [
+    ("<=", BinaryOp::Le),
+    (">=", BinaryOp::Ge),
+    ("==", BinaryOp::Eq),
+    ("!=", BinaryOp::Ne),
+    ("<", BinaryOp::Lt),
+    (">", BinaryOp::Gt),
+    ("+", BinaryOp::Add),
+    ("-", BinaryOp::Sub),
+    ("*", BinaryOp::Mul),
+    ("/", BinaryOp::Div),
+    ("^", BinaryOp::Pow),
+];
+
+/// Parsed expression tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Number(Decimal),
+    Variable(String),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(Func, Vec<Expr>),
+}
+
+impl Expr {
+    /// Parse `source`, rejecting unknown functions and wrong argument counts.
+    pub fn parse(source: &str) -> Result<Self> {
+        crate::expr_parser::parse(source)
+    }
+
+    /// Names of all variables, in first-use order without repeats.
+    pub fn variables(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        let mut stack = vec![self];
+        while let Some(expr) = stack.pop() {
+            match expr {
+                Self::Number(_) => {}
+                Self::Variable(name) if !names.contains(&name.as_str()) => names.push(name),
+                Self::Variable(_) => {}
+                Self::Neg(inner) => stack.push(inner),
+                Self::Binary(_, lhs, rhs) => stack.extend([rhs.as_ref(), lhs.as_ref()]),
+                Self::Call(_, args) => stack.extend(args.iter().rev()),
+            }
+        }
+        names
+    }
+
+    /// Evaluate with variable values supplied by `vars`.
+    pub fn eval(&self, vars: &dyn Fn(&str) -> Option<Decimal>) -> Result<Decimal> {
+        match self {
+            Self::Number(n) => Ok(*n),
+            Self::Variable(name) => {
+                vars(name).ok_or_else(|| Error::MissingInput(format!("{} has no value", name)))
+            }
+            Self::Neg(inner) => Ok(-inner.eval(vars)?),
+            Self::Binary(op, lhs, rhs) => {
+                let (a, b) = (lhs.eval(vars)?, rhs.eval(vars)?);
+                let truth = |t: bool| Ok(if t { Decimal::ONE } else { Decimal::ZERO });
+                match op {
+                    BinaryOp::Add => checked(a.checked_add(b), "sum"),
+                    BinaryOp::Sub => checked(a.checked_sub(b), "difference"),
+                    BinaryOp::Mul => checked(a.checked_mul(b), "product"),
+                    BinaryOp::Div => checked(a.checked_div(b), &format!("{} / {}", a, b)),
+                    BinaryOp::Pow => math::pow(a, b),
+                    BinaryOp::Lt => truth(a < b),
+                    BinaryOp::Le => truth(a <= b),
+                    BinaryOp::Gt => truth(a > b),
+                    BinaryOp::Ge => truth(a >= b),
+                    BinaryOp::Eq => truth(a == b),
+                    BinaryOp::Ne => truth(a != b),
+                }
+            }
+            Self::Call(func, args) => func.apply(args, vars),
+        }
+    }
+}
+
+/// `source` with every variable replaced by its value, for showing working.
+pub fn substitute(source: &str, vars: &dyn Fn(&str) -> Option<Decimal>) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(c) = rest.chars().next() {
+        let is_ident = c.is_alphabetic() || c == '_';
+        let is_number = c.is_ascii_digit() || c == '.';
+        let len = match c {
+            _ if is_ident => rest.find(|d: char| !(d.is_alphanumeric() || d == '_')),
+            // Letters directly after a number stay with it, so `2og` is left as written.
+            _ if is_number => rest.find(|d: char| !(d.is_alphanumeric() || d == '_' || d == '.')),
+            _ => Some(c.len_utf8()),
+        };
+        let (token, tail) = rest.split_at(len.unwrap_or(rest.len()));
+        match vars(token) {
+            Some(value) if is_ident && !tail.trim_start().starts_with('(') => {
+                out += &value.normalize().to_string()
+            }
+            _ => out += token,
+        }
+        rest = tail;
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "expr_tests.rs"]
+mod tests;
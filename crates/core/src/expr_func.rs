@@ -0,0 +1,95 @@
+//! Built-in functions and checked helpers for [`Expr`](crate::expr::Expr).
+
+use crate::expr::Expr;
+use crate::{math, Error, Result};
+use rust_decimal::prelude::*;
+
+/// Built-in function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Func {
+    /// `pow(base, exponent)`
+    Pow,
+    Sqrt,
+    /// Natural logarithm.
+    Ln,
+    Exp,
+    Abs,
+    /// Smallest of two or more arguments.
+    Min,
+    /// Largest of two or more arguments.
+    Max,
+    /// `round(x, decimal_places)`
+    Round,
+    /// `if(condition, then, else)`; only the chosen branch is evaluated.
+    If,
+}
+
+impl Func {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "pow" => Self::Pow,
+            "sqrt" => Self::Sqrt,
+            "ln" => Self::Ln,
+            "exp" => Self::Exp,
+            "abs" => Self::Abs,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            "round" => Self::Round,
+            "if" => Self::If,
+            _ => return None,
+        })
+    }
+
+    /// Accepted argument count as `(min, max)`.
+    pub fn arity(self) -> (usize, usize) {
+        match self {
+            Self::Sqrt | Self::Ln | Self::Exp | Self::Abs => (1, 1),
+            Self::Pow | Self::Round => (2, 2),
+            Self::Min | Self::Max => (2, usize::MAX),
+            Self::If => (3, 3),
+        }
+    }
+
+    /// Evaluate a call; the parser has already checked the argument count.
+    pub(crate) fn apply(
+        self,
+        args: &[Expr],
+        vars: &dyn Fn(&str) -> Option<Decimal>,
+    ) -> Result<Decimal> {
+        let arg = |i: usize| match args.get(i) {
+            Some(expr) => expr.eval(vars),
+            None => Err(Error::Calculation(format!("{:?} is missing argument {}", self, i + 1))),
+        };
+        match self {
+            Self::Pow => math::pow(arg(0)?, arg(1)?),
+            Self::Sqrt => math::sqrt(arg(0)?),
+            Self::Ln => math::ln(arg(0)?),
+            Self::Exp => math::exp(arg(0)?),
+            Self::Abs => Ok(arg(0)?.abs()),
+            Self::Min | Self::Max => {
+                let mut best = arg(0)?;
+                for i in 1..args.len() {
+                    let value = arg(i)?;
+                    if (self == Self::Min) == (value < best) {
+                        best = value;
+                    }
+                }
+                Ok(best)
+            }
+            Self::Round => {
+                let places = arg(1)?;
+                match places.to_u32().filter(|dp| places.fract().is_zero() && *dp <= 28) {
+                    Some(dp) => Ok(arg(0)?.round_dp(dp)),
+                    None => Err(Error::Calculation(format!("Cannot round to {} places", places))),
+                }
+            }
+            Self::If if !arg(0)?.is_zero() => arg(1),
+            Self::If => arg(2),
+        }
+    }
+}
+
+pub(crate) fn checked(value: Option<Decimal>, what: &str) -> Result<Decimal> {
+    value.ok_or_else(|| Error::Calculation(format!("{} is undefined or overflows", what)))
+}
+
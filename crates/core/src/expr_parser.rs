@@ -0,0 +1,149 @@
+//! Tokenizer and recursive-descent parser for [`Expr`].
+
+use crate::expr::{BinaryOp, Expr, Func, OPERATORS};
+use crate::{Error, Result};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Longest expression accepted, in bytes.
+const MAX_LENGTH: usize = 4096;
+/// Deepest nesting accepted, keeping recursion well away from the stack limit.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Number(Decimal),
+    Ident(String),
+    Op(BinaryOp),
+    Open,
+    Close,
+    Comma,
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let word = |end: Option<&(usize, char)>| &source[i..end.map_or(source.len(), |(j, _)| *j)];
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            _ if c.is_ascii_digit() || c == '.' => {
+                while chars.next_if(|(_, d)| d.is_ascii_digit() || *d == '.').is_some() {}
+                let text = word(chars.peek());
+                let number = Decimal::from_str(text)
+                    .map_err(|_| Error::Parse(format!("Invalid number '{}'", text)))?;
+                Token::Number(number)
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                while chars.next_if(|(_, d)| d.is_alphanumeric() || *d == '_').is_some() {}
+                Token::Ident(word(chars.peek()).to_string())
+            }
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            _ => match OPERATORS.iter().find(|(symbol, _)| source[i..].starts_with(symbol)) {
+                Some((symbol, op)) => {
+                    if symbol.len() == 2 {
+                        chars.next();
+                    }
+                    Token::Op(*op)
+                }
+                None => return Err(Error::Parse(format!("Unexpected '{}' at position {}", c, i))),
+            },
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: std::iter::Peekable<std::vec::IntoIter<Token>>,
+    depth: usize,
+}
+
+impl Parser {
+    /// Consume the next token if it is one of `ops`.
+    fn op(&mut self, ops: &[BinaryOp]) -> Option<BinaryOp> {
+        match self.tokens.next_if(|t| matches!(t, Token::Op(op) if ops.contains(op)))? {
+            Token::Op(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    fn binary(&mut self, ops: &[BinaryOp], next: fn(&mut Self) -> Result<Expr>) -> Result<Expr> {
+        let mut lhs = next(self)?;
+        while let Some(op) = self.op(ops) {
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(next(self)?));
+        }
+        Ok(lhs)
+    }
+
+    fn comparison(&mut self) -> Result<Expr> {
+        use BinaryOp::*;
+        let additive = |p: &mut Self| p.binary(&[Add, Sub], |p| p.binary(&[Mul, Div], Self::unary));
+        let lhs = additive(self)?;
+        match self.op(&[Lt, Le, Gt, Ge, Eq, Ne]) {
+            Some(op) => Ok(Expr::Binary(op, Box::new(lhs), Box::new(additive(self)?))),
+            None => Ok(lhs),
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(Error::Parse("Expression is nested too deeply".into()));
+        }
+        let expr = match self.op(&[BinaryOp::Sub]) {
+            Some(_) => Expr::Neg(Box::new(self.unary()?)),
+            None => match (self.primary()?, self.op(&[BinaryOp::Pow])) {
+                (base, Some(op)) => Expr::Binary(op, Box::new(base), Box::new(self.unary()?)),
+                (base, None) => base,
+            },
+        };
+        self.depth -= 1;
+        Ok(expr)
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        match self.tokens.next() {
+            Some(Token::Number(n)) => Ok(Expr::Number(n)),
+            Some(Token::Open) => self.closed(Self::comparison),
+            Some(Token::Ident(name)) if self.tokens.next_if_eq(&Token::Open).is_some() => {
+                let unknown = || Error::Parse(format!("Unknown function {}", name));
+                let func = Func::from_name(&name).ok_or_else(unknown)?;
+                let mut args = vec![self.comparison()?];
+                while self.tokens.next_if_eq(&Token::Comma).is_some() {
+                    args.push(self.comparison()?);
+                }
+                let (min, max) = func.arity();
+                if !(min..=max).contains(&args.len()) {
+                    return Err(Error::Parse(format!("Wrong number of arguments to {}", name)));
+                }
+                self.closed(|_| Ok(Expr::Call(func, args)))
+            }
+            Some(Token::Ident(name)) => Ok(Expr::Variable(name)),
+            other => Err(Error::Parse(format!("Expected a value, found {:?}", other))),
+        }
+    }
+
+    /// Run `inner`, then require a closing parenthesis.
+    fn closed(&mut self, inner: impl FnOnce(&mut Self) -> Result<Expr>) -> Result<Expr> {
+        let expr = inner(self)?;
+        match self.tokens.next() {
+            Some(Token::Close) => Ok(expr),
+            other => Err(Error::Parse(format!("Expected ')', found {:?}", other))),
+        }
+    }
+}
+
+pub(crate) fn parse(source: &str) -> Result<Expr> {
+    if source.len() > MAX_LENGTH {
+        return Err(Error::Parse(format!("Expression longer than {} bytes", MAX_LENGTH)));
+    }
+    let mut parser = Parser { tokens: tokenize(source)?.into_iter().peekable(), depth: 0 };
+    let expr = parser.comparison()?;
+    match parser.tokens.next() {
+        None => Ok(expr),
+        Some(token) => Err(Error::Parse(format!("Unexpected {:?} after expression", token))),
+    }
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+fn eval(source: &str) -> Result<Decimal> {
+    let vars = |name: &str| match name {
+        "og" => Some(Decimal::new(1100, 3)),
+        "fg" => Some(Decimal::new(1010, 3)),
+        "x" => Some(Decimal::from(-4)),
+        _ => None,
+    };
+    Expr::parse(source)?.eval(&vars)
+}
+
+fn dec(source: &str) -> Decimal {
+    source.parse().unwrap_or_default()
+}
+
+#[test]
+fn test_precedence_and_associativity() -> Result<()> {
+    assert_eq!(eval("1 + 2 * 3")?, Decimal::from(7));
+    assert_eq!(eval("(1 + 2) * 3")?, Decimal::from(9));
+    assert_eq!(eval("10 - 4 - 3")?, Decimal::from(3));
+    assert_eq!(eval("2 ^ 3 ^ 2")?, Decimal::from(512));
+    assert_eq!(eval("-2 ^ 2")?, Decimal::from(-4));
+    assert_eq!(eval("2 ^ -1")?, dec("0.5"));
+    Ok(())
+}
+
+#[test]
+fn test_decimal_semantics_and_variables() -> Result<()> {
+    assert_eq!(eval("0.1 + 0.2")?, dec("0.3"));
+    assert_eq!(eval("(og - fg) * 131.25")?, dec("11.81250"));
+    assert_eq!(Expr::parse("og - fg * og + x")?.variables(), ["og", "fg", "x"]);
+    Ok(())
+}
+
+#[test]
+fn test_functions() -> Result<()> {
+    assert_eq!(eval("sqrt(16)")?, Decimal::from(4));
+    assert_eq!(eval("pow(x, 2)")?, Decimal::from(16));
+    assert_eq!(eval("round(pow(2, 0.5), 4)")?, dec("1.4142"));
+    assert_eq!(eval("round(ln(exp(2)), 6)")?, Decimal::TWO);
+    assert_eq!(eval("min(3, x, 1)")?, Decimal::from(-4));
+    assert_eq!(eval("max(3, x, 1)")?, Decimal::from(3));
+    assert_eq!(eval("abs(x)")?, Decimal::from(4));
+    Ok(())
+}
+
+#[test]
+fn test_comparisons_and_lazy_if() -> Result<()> {
+    assert_eq!(eval("og > fg")?, Decimal::ONE);
+    assert_eq!(eval("og <= fg")?, Decimal::ZERO);
+    assert_eq!(eval("if(x >= 0, sqrt(x), -1)")?, Decimal::NEGATIVE_ONE);
+    assert_eq!(eval("if(x != 0, 10 / x, 0)")?, dec("-2.5"));
+    Ok(())
+}
+
+#[test]
+fn test_substitute_variables_only() {
+    let vars = |name: &str| (name == "og" || name == "max").then_some(Decimal::new(1100, 3));
+    let text = substitute("max(og, 2og) * og2 + max", &vars);
+    assert_eq!(text, "max(1.1, 2og) * og2 + 1.1");
+}
+
+#[test]
+fn test_runtime_errors() {
+    for source in ["1 / 0", "sqrt(x)", "ln(0)", "round(1, 0.5)", "pow(10, 100)"] {
+        assert!(matches!(eval(source), Err(Error::Calculation(_))), "{source}");
+    }
+    assert!(matches!(eval("missing + 1"), Err(Error::MissingInput(_))));
+}
+
+#[test]
+fn test_parse_errors() {
+    let deep = format!("{}1{}", "(".repeat(100), ")".repeat(100));
+    let long = "1+".repeat(3000) + "1";
+    for source in ["", "1 +", "(1", "1)", "2 $ 3", "1..2", "foo(1)", "sqrt(1, 2)", "min(1)"] {
+        assert!(matches!(Expr::parse(source), Err(Error::Parse(_))), "{source}");
+    }
+    assert!(matches!(Expr::parse("1 < 2 < 3"), Err(Error::Parse(_))));
+    assert!(matches!(Expr::parse(&deep), Err(Error::Parse(_))));
+    assert!(matches!(Expr::parse(&long), Err(Error::Parse(_))));
+}
@@ -0,0 +1,119 @@
+//! Calculators defined at runtime by an expression, typically loaded from TOML.
+
+use crate::expr::{substitute, Expr};
+use crate::{
+    CalcInput, CalcResult, Calculator, Category, Error, FormulaDefinition, FormulaInput,
+    Measurement, Result, Unit,
+};
+
+/// Calculator that evaluates a [`FormulaDefinition`].
+pub struct FormulaCalculator {
+    id: String,
+    name: String,
+    description: String,
+    category: Category,
+    tags: Vec<String>,
+    inputs: Vec<FormulaInput>,
+    expression: Expr,
+    source: String,
+    unit: Unit,
+    version: u32,
+}
+
+impl FormulaCalculator {
+    /// Parse the expression and check it only uses declared inputs.
+    pub fn new(definition: FormulaDefinition) -> Result<Self> {
+        let id = definition.id.trim();
+        let invalid = |msg: String| Error::Config(format!("Formula '{}': {}", id, msg));
+        if id.is_empty() {
+            return Err(invalid("id must not be empty".into()));
+        }
+        let expression = Expr::parse(&definition.expression).map_err(|e| invalid(e.to_string()))?;
+        let inputs = definition.inputs;
+        for (i, input) in inputs.iter().enumerate() {
+            if inputs[..i].iter().any(|other| other.name == input.name) {
+                return Err(invalid(format!("input '{}' is declared twice", input.name)));
+            }
+            if let (Some(min), Some(max)) = (input.min, input.max)
+                && min > max
+            {
+                return Err(invalid(format!("{} has min {} above max {}", input.name, min, max)));
+            }
+        }
+        if let Some(name) = expression.variables().into_iter().find(|v| {
+            !inputs.iter().any(|input| input.name == *v)
+        }) {
+            return Err(invalid(format!("uses undeclared input '{}'", name)));
+        }
+
+        Ok(Self {
+            id: id.to_string(),
+            name: definition.name,
+            description: definition.description,
+            category: definition.category.unwrap_or(Category::Advanced),
+            tags: definition.tags,
+            inputs,
+            expression,
+            source: definition.expression,
+            unit: definition.unit,
+            version: definition.version,
+        })
+    }
+}
+
+impl Calculator for FormulaCalculator {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn category(&self) -> Category {
+        self.category
+    }
+
+    fn tags(&self) -> Vec<&str> {
+        self.tags.iter().map(String::as_str).collect()
+    }
+
+    fn sort_order(&self) -> u32 {
+        1000
+    }
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        self.validate(&input)?;
+
+        let mut values = Vec::with_capacity(self.inputs.len());
+        for spec in &self.inputs {
+            values.push((spec.name.as_str(), spec.resolve(&input)?));
+        }
+        let lookup = |name: &str| values.iter().find(|(n, _)| *n == name).map(|(_, v)| *v);
+        let output = self.expression.eval(&lookup)?;
+        let working = substitute(&self.source, &lookup);
+        Ok(CalcResult::new(Measurement::new(output, self.unit))
+            .with_step(self.name.as_str(), self.source.as_str(), working, output)
+            .with_meta("formula", self.source.as_str())
+            .with_meta("source", "user"))
+    }
+
+    fn validate(&self, input: &CalcInput) -> Result<()> {
+        match self.inputs.iter().find(|spec| !spec.is_provided(input)) {
+            Some(spec) => Err(Error::MissingInput(format!("{} required", spec.name))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "formula_tests.rs"]
+mod tests;
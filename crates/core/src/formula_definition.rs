@@ -0,0 +1,79 @@
+//! Serializable definitions of user formula calculators.
+
+use crate::{CalcInput, Category, Error, Result, Unit};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Named formula input with an optional unit, allowed range and default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormulaInput {
+    pub name: String,
+    /// When set, a measurement in this unit is used if the parameter is absent.
+    #[serde(default)]
+    pub unit: Option<Unit>,
+    #[serde(default)]
+    pub min: Option<Decimal>,
+    #[serde(default)]
+    pub max: Option<Decimal>,
+    /// Used when neither the parameter nor a measurement is given.
+    #[serde(default)]
+    pub default: Option<Decimal>,
+}
+
+/// Declarative definition of a formula calculator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormulaDefinition {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub category: Option<Category>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub inputs: Vec<FormulaInput>,
+    pub expression: String,
+    /// Unit of the result.
+    pub unit: Unit,
+    /// Formula version; bump when changing the expression.
+    #[serde(default = "first_version")]
+    pub version: u32,
+}
+
+fn first_version() -> u32 {
+    1
+}
+
+impl FormulaInput {
+    /// Whether `input` supplies this value or a default covers it.
+    pub fn is_provided(&self, input: &CalcInput) -> bool {
+        input.get_param(&self.name).is_some()
+            || self.unit.is_some_and(|unit| input.get_measurement(unit).is_ok())
+            || self.default.is_some()
+    }
+
+    /// Value from the parameter, then a measurement in `unit`, then the default,
+    /// checked against the declared range.
+    pub fn resolve(&self, input: &CalcInput) -> Result<Decimal> {
+        let measured = self.unit.and_then(|unit| input.get_measurement(unit).ok());
+        let value = match (input.get_param(&self.name), measured, self.default) {
+            (Some(_), _, _) => input.get_decimal(&self.name)?,
+            (None, Some(m), _) => m.value,
+            (None, None, Some(default)) => default,
+            (None, None, None) => {
+                return Err(Error::MissingInput(format!("{} required", self.name)));
+            }
+        };
+        if self.min.is_some_and(|min| value < min) || self.max.is_some_and(|max| value > max) {
+            let bound = |b: Option<Decimal>| b.map_or("…".to_string(), |b| b.to_string());
+            return Err(Error::OutOfRange(format!(
+                "{} = {} is outside {} to {}",
+                self.name,
+                value,
+                bound(self.min),
+                bound(self.max)
+            )));
+        }
+        Ok(value)
+    }
+}
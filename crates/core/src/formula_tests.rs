@@ -0,0 +1,94 @@
+use super::*;
+use crate::{calculator_handles, CalculatorHandle, find_calculator, find_handle, register_user_calculators};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+fn input(name: &str) -> FormulaInput {
+    FormulaInput { name: name.into(), unit: None, min: None, max: None, default: None }
+}
+
+fn definition(id: &str, expression: &str, inputs: Vec<FormulaInput>) -> FormulaDefinition {
+    FormulaDefinition {
+        id: id.into(),
+        name: format!("House {}", id),
+        description: "Test formula".into(),
+        category: None,
+        tags: vec!["house".into()],
+        inputs,
+        expression: expression.into(),
+        unit: Unit::Abv,
+        version: 2,
+    }
+}
+
+fn hall() -> Result<FormulaCalculator> {
+    let og = FormulaInput { min: Some(Decimal::ONE), max: Some(Decimal::TWO), ..input("og") };
+    let fg = FormulaInput { unit: Some(Unit::SpecificGravity), ..input("fg") };
+    let factor = FormulaInput { default: Some(Decimal::new(13125, 2)), ..input("factor") };
+    FormulaCalculator::new(definition("house_abv", "(og - fg) * factor", vec![og, fg, factor]))
+}
+
+#[test]
+fn test_evaluates_params_measurements_and_defaults() -> Result<()> {
+    let calc = hall()?;
+    let sg = Measurement::new(Decimal::new(1010, 3), Unit::SpecificGravity);
+    let result = calc.calculate(CalcInput::new().add_param("og", "1.100").add_measurement(sg))?;
+    assert_eq!(result.output, Measurement::new(Decimal::new(1181250, 5), Unit::Abv));
+    assert!(result.metadata.contains(&("formula".into(), "(og - fg) * factor".into())));
+    assert_eq!(result.trace[0].substituted, "(1.1 - 1.01) * 131.25");
+    let scaled = CalcInput::new().add_param("og", "1.1").add_param("fg", "1");
+    let scaled = scaled.add_param("factor", "2");
+    assert_eq!(calc.calculate(scaled)?.output.value, Decimal::new(2, 1));
+    assert_eq!((calc.category(), calc.tags()), (Category::Advanced, vec!["house"]));
+    assert_eq!(calc.version(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_missing_and_out_of_range_inputs() -> Result<()> {
+    let calc = hall()?;
+    let missing = calc.calculate(CalcInput::new().add_param("og", "1.1"));
+    assert!(matches!(missing, Err(Error::MissingInput(_))));
+    let high = calc.calculate(CalcInput::new().add_param("og", "2.5").add_param("fg", "1"));
+    assert!(matches!(high, Err(Error::OutOfRange(_))));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_definitions() {
+    let inverted = FormulaInput { max: Some(Decimal::ONE), ..input("x") };
+    let bad = [
+        definition("", "1", vec![]),
+        definition("a", "x +", vec![input("x")]),
+        definition("b", "x + y", vec![input("x")]),
+        definition("c", "x", vec![input("x"), input("x")]),
+        definition("d", "x", vec![FormulaInput { min: Some(Decimal::TEN), ..inverted }]),
+    ];
+    for definition in bad {
+        assert!(matches!(FormulaCalculator::new(definition), Err(Error::Config(_))));
+    }
+}
+
+#[test]
+fn test_user_registration_replaces_set() -> Result<()> {
+    let first: Box<dyn Calculator> = Box::new(hall()?);
+    register_user_calculators(vec![first])?;
+    assert!(find_handle("house_abv").is_some_and(|c| c.name() == "House house_abv"));
+    assert!(calculator_handles().iter().any(|c| c.id() == "house_abv"));
+    assert!(find_calculator("house_abv").is_none());
+
+    let twice: Vec<Box<dyn Calculator>> = vec![Box::new(hall()?), Box::new(hall()?)];
+    assert!(matches!(register_user_calculators(twice), Err(Error::Config(_))));
+    assert!(find_handle("house_abv").is_some());
+
+    let replaced = match find_handle("house_abv") {
+        Some(CalculatorHandle::User(calc)) => Some(Arc::downgrade(&calc)),
+        _ => None,
+    };
+    let other = FormulaCalculator::new(definition("house_tsa", "x * 2", vec![input("x")]))?;
+    register_user_calculators(vec![Box::new(other)])?;
+    assert!(find_handle("house_abv").is_none());
+    assert!(replaced.is_some_and(|weak| weak.upgrade().is_none()));
+    assert!(find_handle("house_tsa").is_some());
+    register_user_calculators(Vec::new())
+}
@@ -0,0 +1,150 @@
+//! Goal seek: solve any calculator for one input given a target primary output.
+
+use crate::{math, CalcInput, CalcResult, Calculator, Error, Result};
+use rust_decimal::Decimal;
+
+/// Outcome of a goal-seek run.
+#[derive(Debug, Clone)]
+pub struct GoalSeekSolution {
+    /// Solved value of the free parameter.
+    pub value: Decimal,
+    /// Primary output at `value`.
+    pub output: Decimal,
+    /// `output − target`.
+    pub residual: Decimal,
+    /// Calculator evaluations after the two bounds.
+    pub iterations: u32,
+    /// Whether `|residual|` reached the tolerance.
+    pub converged: bool,
+    /// Full calculator result at `value`.
+    pub result: CalcResult,
+}
+
+/// Numeric inverse of a calculator over one bracketed input parameter.
+///
+/// Uses Illinois false position (a secant step kept inside the bracket) and
+/// falls back to bisection whenever the secant step is unusable, so it
+/// converges for any continuous output whose target lies between the outputs
+/// at the two bounds.
+pub struct GoalSeek<'a> {
+    calculator: &'a dyn Calculator,
+    input: CalcInput,
+    param: String,
+    lower: Decimal,
+    upper: Decimal,
+    target: Decimal,
+    tolerance: Decimal,
+    max_iterations: u32,
+}
+
+impl<'a> GoalSeek<'a> {
+    /// Solve `param` within `(lower, upper)` so the primary output equals `target`.
+    pub fn new(
+        calculator: &'a dyn Calculator,
+        input: CalcInput,
+        param: impl Into<String>,
+        bounds: (Decimal, Decimal),
+        target: Decimal,
+    ) -> Self {
+        Self {
+            calculator,
+            input,
+            param: param.into(),
+            lower: bounds.0,
+            upper: bounds.1,
+            target,
+            tolerance: Decimal::new(1, 6),
+            max_iterations: 100,
+        }
+    }
+
+    /// Acceptable `|output − target|` (default 0.000001).
+    pub fn with_tolerance(mut self, tolerance: Decimal) -> Self {
+        self.tolerance = tolerance.abs();
+        self
+    }
+
+    /// Evaluation budget after the two bounds (default 100).
+    pub fn with_max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    fn eval(&self, x: Decimal) -> Result<(Decimal, CalcResult)> {
+        let input = self.input.clone().set_param(self.param.as_str(), x.to_string());
+        let result = self.calculator.calculate(input)?;
+        Ok((math::sub(result.output.value, self.target)?, result))
+    }
+
+    fn solution(&self, x: Decimal, fx: Decimal, result: CalcResult, i: u32) -> GoalSeekSolution {
+        GoalSeekSolution {
+            value: x,
+            output: result.output.value,
+            residual: fx,
+            iterations: i,
+            converged: fx.abs() <= self.tolerance,
+            result,
+        }
+    }
+
+    /// Run the search.
+    pub fn solve(&self) -> Result<GoalSeekSolution> {
+        if self.lower >= self.upper {
+            return Err(Error::Validation(format!(
+                "Lower bound {} must be below upper bound {}",
+                self.lower, self.upper
+            )));
+        }
+        let (mut a, mut b) = (self.lower, self.upper);
+        let (mut fa, ra) = self.eval(a)?;
+        let (mut fb, rb) = self.eval(b)?;
+        if fa.abs() <= self.tolerance {
+            return Ok(self.solution(a, fa, ra, 0));
+        }
+        if fb.abs() <= self.tolerance {
+            return Ok(self.solution(b, fb, rb, 0));
+        }
+        if fa.is_sign_negative() == fb.is_sign_negative() {
+            return Err(Error::Calculation(format!(
+                "Target {} not bracketed: {} gives {} to {} over the bounds",
+                self.target, self.param, ra.output.value, rb.output.value
+            )));
+        }
+
+        let mut best = if fa.abs() < fb.abs() { (a, fa, ra) } else { (b, fb, rb) };
+        let mut kept = 0i8;
+        for i in 1..=self.max_iterations {
+            let span = math::sub(fb, fa)?;
+            let secant = (b - a).checked_mul(fb).and_then(|s| b.checked_sub(s.checked_div(span)?));
+            let x = secant.filter(|x| *x > a && *x < b).unwrap_or((a + b) / Decimal::TWO);
+            let (fx, rx) = self.eval(x)?;
+            if fx.abs() <= self.tolerance || x == a || x == b {
+                return Ok(self.solution(x, fx, rx, i));
+            }
+            if fx.abs() < best.1.abs() {
+                best = (x, fx, rx.clone());
+            }
+            // Illinois: halve the stale end's residual when the same side is kept twice.
+            if fx.is_sign_negative() == fb.is_sign_negative() {
+                (b, fb) = (x, fx);
+                if kept == -1 {
+                    fa /= Decimal::TWO;
+                }
+                kept = -1;
+            } else {
+                (a, fa) = (x, fx);
+                if kept == 1 {
+                    fb /= Decimal::TWO;
+                }
+                kept = 1;
+            }
+        }
+
+        let (x, fx, rx) = best;
+        Ok(self.solution(x, fx, rx, self.max_iterations))
+    }
+}
+
+#[cfg(test)]
+#[path = "goal_seek_tests.rs"]
+mod tests;
@@ -0,0 +1,94 @@
+use super::*;
+use crate::{Measurement, Unit};
+
+/// y = x³ + k·x, strictly increasing for k ≥ 0.
+struct Cubic;
+
+impl Calculator for Cubic {
+    fn id(&self) -> &'static str {
+        "cubic"
+    }
+    fn name(&self) -> &'static str {
+        "Cubic"
+    }
+    fn description(&self) -> &'static str {
+        "x³ + k·x"
+    }
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        let x = input.get_decimal("x")?;
+        let k = input.get_decimal_or("k", Decimal::ZERO)?;
+        if x > Decimal::from(100) {
+            return Err(Error::OutOfRange("x above 100".into()));
+        }
+        Ok(CalcResult::new(Measurement::new(x * x * x + k * x, Unit::Percent)))
+    }
+}
+
+fn seek(input: CalcInput, bounds: (i64, i64), target: i64) -> GoalSeek<'static> {
+    let bounds = (Decimal::from(bounds.0), Decimal::from(bounds.1));
+    GoalSeek::new(&Cubic, input, "x", bounds, Decimal::from(target))
+}
+
+#[test]
+fn test_solves_cube_root() -> Result<()> {
+    let solution = seek(CalcInput::new(), (0, 10), 27).solve()?;
+    assert!(solution.converged);
+    assert_eq!(solution.value.round_dp(5), Decimal::from(3));
+    assert!(solution.residual.abs() <= Decimal::new(1, 6));
+    assert!(solution.iterations < 40);
+    Ok(())
+}
+
+#[test]
+fn test_keeps_fixed_inputs() -> Result<()> {
+    // x³ + 2x = 12 at x = 2.
+    let input = CalcInput::new().add_param("k", "2").add_param("x", "99");
+    let solution = seek(input, (-5, 5), 12).solve()?;
+    assert_eq!(solution.value.round_dp(5), Decimal::TWO);
+    assert_eq!(solution.result.output.value, solution.output);
+    Ok(())
+}
+
+#[test]
+fn test_decreasing_bracket_and_exact_bound() -> Result<()> {
+    let solution = seek(CalcInput::new(), (-4, 0), -8).solve()?;
+    assert_eq!(solution.value.round_dp(5), Decimal::from(-2));
+    let exact = seek(CalcInput::new(), (2, 6), 8).solve()?;
+    assert_eq!((exact.value, exact.iterations), (Decimal::TWO, 0));
+    Ok(())
+}
+
+#[test]
+fn test_tolerance_and_iteration_budget() -> Result<()> {
+    let loose = seek(CalcInput::new(), (0, 10), 27).with_tolerance(Decimal::ONE).solve()?;
+    assert!(loose.converged && loose.residual.abs() <= Decimal::ONE);
+    let capped = seek(CalcInput::new(), (0, 10), 27)
+        .with_tolerance(Decimal::ZERO)
+        .with_max_iterations(2)
+        .solve()?;
+    assert!(!capped.converged);
+    assert_eq!(capped.iterations, 2);
+    Ok(())
+}
+
+#[test]
+fn test_unbracketed_target() {
+    let result = seek(CalcInput::new(), (0, 2), 27).solve();
+    assert!(matches!(result, Err(Error::Calculation(_))));
+}
+
+#[test]
+fn test_invalid_bounds_and_calculator_errors() {
+    assert!(matches!(seek(CalcInput::new(), (5, 5), 1).solve(), Err(Error::Validation(_))));
+    assert!(matches!(seek(CalcInput::new(), (0, 200), 27).solve(), Err(Error::OutOfRange(_))));
+}
+
+#[test]
+fn test_overflow_is_an_error() {
+    let bounds = (Decimal::ONE, Decimal::TWO);
+    let result = GoalSeek::new(&Cubic, CalcInput::new(), "x", bounds, Decimal::MIN).solve();
+    assert!(matches!(result, Err(Error::Calculation(_))));
+    // ±5e28 at the bounds: the residual span fb − fa exceeds Decimal::MAX.
+    let steep = CalcInput::new().add_param("k", "500000000000000000000000000");
+    assert!(matches!(seek(steep, (-100, 100), 0).solve(), Err(Error::Calculation(_))));
+}
@@ -0,0 +1,97 @@
+//! Calculation input: measurements and named parameters.
+
+use crate::{Error, Measurement, Quantity, Result, Unit};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Input parameters for calculations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalcInput {
+    pub measurements: Vec<Measurement>,
+    pub params: Vec<(String, String)>,
+}
+
+impl CalcInput {
+    pub fn new() -> Self {
+        Self {
+            measurements: Vec::new(),
+            params: Vec::new(),
+        }
+    }
+
+    pub fn add_measurement(mut self, m: Measurement) -> Self {
+        self.measurements.push(m);
+        self
+    }
+
+    pub fn add_param(mut self, k: impl Into<String>, v: impl Into<String>) -> Self {
+        self.params.push((k.into(), v.into()));
+        self
+    }
+
+    /// Set a parameter, replacing any existing value for the key.
+    pub fn set_param(mut self, k: impl Into<String>, v: impl Into<String>) -> Self {
+        let (k, v) = (k.into(), v.into());
+        match self.params.iter_mut().find(|(key, _)| *key == k) {
+            Some(entry) => entry.1 = v,
+            None => self.params.push((k, v)),
+        }
+        self
+    }
+
+    pub fn get_measurement(&self, unit: Unit) -> Result<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|m| m.unit == unit)
+            .ok_or(Error::MissingInput(format!("No measurement with unit {}", unit)))
+    }
+
+    /// First measurement `Q` can read, converted to its base unit.
+    pub fn get_measured<Q: Quantity>(&self) -> Result<Q> {
+        match self.measurements.iter().find(|m| Q::accepts(m.unit)) {
+            Some(m) => Q::from_measurement(*m),
+            None => Err(Error::MissingInput(format!("No {} measurement", Q::NAME))),
+        }
+    }
+
+    pub fn get_param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Parse a required parameter as a decimal.
+    pub fn get_decimal(&self, key: &str) -> Result<Decimal> {
+        let raw = self
+            .get_param(key)
+            .ok_or_else(|| Error::MissingInput(format!("{} parameter required", key)))?;
+        raw.trim()
+            .parse()
+            .map_err(|_| Error::Parse(format!("Invalid {}: {}", key, raw)))
+    }
+
+    /// Parse a required parameter as a quantity in its base unit, e.g. litres.
+    pub fn get_quantity<Q: Quantity>(&self, key: &str) -> Result<Q> {
+        Q::new(self.get_decimal(key)?)
+    }
+
+    /// Parse an optional parameter as a quantity, falling back to `default` in the base unit.
+    pub fn get_quantity_or<Q: Quantity>(&self, key: &str, default: Decimal) -> Result<Q> {
+        Q::new(self.get_decimal_or(key, default)?)
+    }
+
+    /// Parse an optional parameter as a decimal, falling back to `default`.
+    pub fn get_decimal_or(&self, key: &str, default: Decimal) -> Result<Decimal> {
+        match self.get_param(key) {
+            Some(_) => self.get_decimal(key),
+            None => Ok(default),
+        }
+    }
+}
+
+impl Default for CalcInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -4,10 +4,31 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+pub mod category;
 pub mod error;
+pub mod expr;
+mod expr_func;
+mod expr_parser;
+pub mod formula;
+pub mod formula_definition;
+pub mod goal_seek;
+pub mod input;
+pub mod math;
+pub mod pipeline;
+pub mod pipeline_step;
+pub mod quantity;
+pub mod record;
+pub mod registry;
+pub mod result;
+pub mod search;
+pub mod sensitivity;
+pub mod sweep;
+pub mod temperature;
 pub mod traits;
 pub mod units;
+pub mod user_calculators;
 pub mod validation;
+pub mod volume;
 
 #[cfg(test)]
 mod calc_input_tests;
@@ -16,10 +37,30 @@ mod calc_result_tests;
 #[cfg(test)]
 mod units_tests;
 
+pub use category::Category;
 pub use error::{Error, Result};
+pub use expr::{substitute, Expr};
+pub use formula::FormulaCalculator;
+pub use formula_definition::{FormulaDefinition, FormulaInput};
+pub use goal_seek::{GoalSeek, GoalSeekSolution};
+pub use input::CalcInput;
+pub use pipeline::{Pipeline, PipelineRun};
+pub use pipeline_step::PipelineStep;
+pub use quantity::{Brix, Ph, Quantity, Sg};
+pub use record::{CalculationRecord, Replay};
+pub use registry::{find_calculator, get_all_calculators};
+pub use result::{CalcResult, SecondaryResult, TraceStep};
+pub use search::{list_by_category, search_calculators};
+pub use sensitivity::{Sensitivity, SensitivityEntry, SensitivityReport};
+pub use sweep::{Sweep, SweepGrid, SweepPoint, SweepRange};
+pub use temperature::Temperature;
 pub use traits::Calculator;
 pub use units::*;
+pub use user_calculators::{
+    calculator_handles, find_handle, register_user_calculators, user_calculators, CalculatorHandle,
+};
 pub use validation::*;
+pub use volume::Volume;
 
 /// Measurement with unit and precision.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -64,77 +105,3 @@ impl fmt::Display for Measurement {
         write!(f, "{} {}", self.value, self.unit)
     }
 }
-
-/// Calculation result with metadata.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CalcResult {
-    pub output: Measurement,
-    pub warnings: Vec<String>,
-    pub metadata: Vec<(String, String)>,
-}
-
-impl CalcResult {
-    pub fn new(output: Measurement) -> Self {
-        Self {
-            output,
-            warnings: Vec::new(),
-            metadata: Vec::new(),
-        }
-    }
-
-    pub fn with_warning(mut self, msg: impl Into<String>) -> Self {
-        self.warnings.push(msg.into());
-        self
-    }
-
-    pub fn with_meta(mut self, key: impl Into<String>, val: impl Into<String>) -> Self {
-        self.metadata.push((key.into(), val.into()));
-        self
-    }
-}
-
-/// Input parameters for calculations.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CalcInput {
-    pub measurements: Vec<Measurement>,
-    pub params: Vec<(String, String)>,
-}
-
-impl CalcInput {
-    pub fn new() -> Self {
-        Self {
-            measurements: Vec::new(),
-            params: Vec::new(),
-        }
-    }
-
-    pub fn add_measurement(mut self, m: Measurement) -> Self {
-        self.measurements.push(m);
-        self
-    }
-
-    pub fn add_param(mut self, k: impl Into<String>, v: impl Into<String>) -> Self {
-        self.params.push((k.into(), v.into()));
-        self
-    }
-
-    pub fn get_measurement(&self, unit: Unit) -> Result<&Measurement> {
-        self.measurements
-            .iter()
-            .find(|m| m.unit == unit)
-            .ok_or(Error::MissingInput(format!("No measurement with unit {}", unit)))
-    }
-
-    pub fn get_param(&self, key: &str) -> Option<&str> {
-        self.params
-            .iter()
-            .find(|(k, _)| k == key)
-            .map(|(_, v)| v.as_str())
-    }
-}
-
-impl Default for CalcInput {
-    fn default() -> Self {
-        Self::new()
-    }
-}
@@ -0,0 +1,125 @@
+//! Checked `Decimal` maths for calculators: powers, exponentials, logarithms,
+//! polynomials and table interpolation.
+//!
+//! Functions wrap `rust_decimal`'s series implementations with explicit domain
+//! checks, so a bad argument is an [`Error::Calculation`] rather than a panic or
+//! a silently wrong value (`rust_decimal` gives `(-8)^0.5 = -2.83` and
+//! `0^-1 = 0`). Stated precisions are bounds on the error measured against
+//! 50-digit references; `Decimal` itself keeps at most 28 decimal places.
+
+use crate::{Error, Result};
+use rust_decimal::prelude::*;
+use rust_decimal::MathematicalOps;
+
+fn undefined(what: String) -> Error {
+    Error::Calculation(format!("{} is undefined or overflows", what))
+}
+
+/// e^x. Relative error below 1e-26 for 0 ≤ x ≤ 60; for negative x the
+/// absolute error is below 1e-28.
+///
+/// Results too small for `Decimal` (x below about −64) round to zero; results
+/// too large (x above about 66) are errors.
+pub fn exp(x: Decimal) -> Result<Decimal> {
+    match x.checked_exp() {
+        Some(value) => Ok(value),
+        None if x.is_sign_negative() => Ok(Decimal::ZERO),
+        None => Err(undefined(format!("exp({})", x))),
+    }
+}
+
+/// Natural logarithm. Absolute error below 1e-25 for 1e-20 ≤ x ≤ 1e20; errors
+/// unless x > 0.
+pub fn ln(x: Decimal) -> Result<Decimal> {
+    match x > Decimal::ZERO {
+        true => x.checked_ln().ok_or_else(|| undefined(format!("ln({})", x))),
+        false => Err(undefined(format!("ln({})", x))),
+    }
+}
+
+/// Base-10 logarithm, exact for powers of ten. Absolute error below 1e-25 for
+/// 1e-20 ≤ x ≤ 1e20; errors unless x > 0.
+pub fn log10(x: Decimal) -> Result<Decimal> {
+    match x > Decimal::ZERO {
+        true => x.checked_log10().ok_or_else(|| undefined(format!("log10({})", x))),
+        false => Err(undefined(format!("log10({})", x))),
+    }
+}
+
+/// Square root, exact for perfect squares. Relative error below 1e-26 for
+/// non-negative x; errors for negative x.
+pub fn sqrt(x: Decimal) -> Result<Decimal> {
+    x.sqrt().ok_or_else(|| undefined(format!("sqrt({})", x)))
+}
+
+/// base^exponent.
+///
+/// Whole exponents use repeated multiplication and are exact unless the result
+/// needs more than 28 digits; results too small for `Decimal` round to zero, as
+/// in [`exp`]. Fractional exponents use e^(exponent × ln base),
+/// with relative error below 1e-25 for results from 0.001 to 1e20 and absolute
+/// error below 1e-28 for smaller ones. Zero to a negative power and negative
+/// bases with fractional exponents are errors.
+pub fn pow(base: Decimal, exponent: Decimal) -> Result<Decimal> {
+    let fail = || undefined(format!("{} ^ {}", base, exponent));
+    if base.is_zero() && exponent.is_sign_negative() {
+        return Err(fail());
+    }
+    if exponent.fract().is_zero() {
+        let n = exponent.to_i64().ok_or_else(fail)?;
+        return match base.checked_powi(n) {
+            Some(value) => Ok(value),
+            None if n < 0 && base.abs() > Decimal::ONE => Ok(Decimal::ZERO),
+            None => Err(fail()),
+        };
+    }
+    if base.is_zero() {
+        return Ok(Decimal::ZERO);
+    }
+    if base.is_sign_negative() {
+        return Err(fail());
+    }
+    exp(ln(base)?.checked_mul(exponent).ok_or_else(fail)?)
+}
+
+/// a − b, exact unless the difference overflows, which is an error.
+pub fn sub(a: Decimal, b: Decimal) -> Result<Decimal> {
+    a.checked_sub(b).ok_or_else(|| undefined(format!("{} − {}", a, b)))
+}
+
+/// Polynomial c₀ + c₁x + c₂x² + … by Horner's rule, coefficients lowest degree
+/// first. Exact apart from `Decimal` rounding of each product; errors on overflow.
+pub fn horner(coefficients: &[Decimal], x: Decimal) -> Result<Decimal> {
+    coefficients.iter().rev().try_fold(Decimal::ZERO, |acc, c| {
+        acc.checked_mul(x)
+            .and_then(|product| product.checked_add(*c))
+            .ok_or_else(|| undefined(format!("Polynomial at {}", x)))
+    })
+}
+
+/// Linear interpolation over `(x, y)` points sorted by strictly increasing x,
+/// clamped to the first and last y outside the table.
+///
+/// Exact apart from one `Decimal` division. Empty or unsorted tables are errors.
+pub fn interpolate(table: &[(Decimal, Decimal)], x: Decimal) -> Result<Decimal> {
+    let (Some(first), Some(last)) = (table.first(), table.last()) else {
+        return Err(Error::Calculation("Interpolation table is empty".into()));
+    };
+    if table.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+        return Err(Error::Calculation("Interpolation table is not sorted by x".into()));
+    }
+    if x <= first.0 {
+        return Ok(first.1);
+    }
+    match table.windows(2).find(|pair| x <= pair[1].0) {
+        Some(pair) => {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            Ok(y0 + (y1 - y0) * (x - x0) / (x1 - x0))
+        }
+        None => Ok(last.1),
+    }
+}
+
+#[cfg(test)]
+#[path = "math_tests.rs"]
+mod tests;
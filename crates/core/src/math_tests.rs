@@ -0,0 +1,74 @@
+use super::*;
+
+fn close(value: Decimal, expected: &str, tolerance: Decimal) -> bool {
+    Decimal::from_str(expected).is_ok_and(|e| (value - e).abs() <= tolerance)
+}
+
+#[test]
+fn test_exp_and_logs_match_references() -> Result<()> {
+    let tight = Decimal::new(1, 26);
+    assert!(close(exp(Decimal::ONE)?, "2.7182818284590452353602874714", tight));
+    assert!(close(exp(Decimal::from(-10))?, "0.0000453999297624848515355915", tight));
+    assert!(close(ln(Decimal::TWO)?, "0.6931471805599453094172321215", tight));
+    assert!(close(log10(Decimal::from(7))?, "0.8450980400142568307122162586", tight));
+    assert_eq!(log10(Decimal::new(1, 3))?, Decimal::from(-3));
+    assert!(close(sqrt(Decimal::TWO)?, "1.4142135623730950488016887242", tight));
+    assert_eq!(sqrt(Decimal::from(144))?, Decimal::from(12));
+    Ok(())
+}
+
+#[test]
+fn test_pow() -> Result<()> {
+    // Molecular SO₂ factor 10^(pH − pKa) at pH 3.5.
+    let factor = pow(Decimal::TEN, Decimal::new(169, 2))?;
+    assert!(close(factor, "48.977881936844619591030856135", Decimal::new(1, 24)));
+    assert_eq!(pow(Decimal::new(15, 1), Decimal::from(3))?, Decimal::new(3375, 3));
+    assert_eq!(pow(Decimal::TWO, Decimal::from(-2))?, Decimal::new(25, 2));
+    assert_eq!(pow(Decimal::ZERO, Decimal::new(5, 1))?, Decimal::ZERO);
+    Ok(())
+}
+
+#[test]
+fn test_domain_errors_instead_of_panics() {
+    let undefined = |r: Result<Decimal>| matches!(r, Err(Error::Calculation(_)));
+    assert!(undefined(ln(Decimal::ZERO)));
+    assert!(undefined(log10(Decimal::from(-1))));
+    assert!(undefined(sqrt(Decimal::from(-4))));
+    assert!(undefined(pow(Decimal::from(-8), Decimal::new(5, 1))));
+    assert!(undefined(pow(Decimal::ZERO, Decimal::from(-1))));
+    assert!(undefined(exp(Decimal::from(70))));
+    assert!(undefined(pow(Decimal::TEN, Decimal::from(40))));
+    assert!(exp(Decimal::from(-70)).is_ok_and(|v| v.is_zero()));
+    assert!(undefined(sub(Decimal::MIN, Decimal::ONE)));
+}
+
+#[test]
+fn test_pow_underflow_rounds_to_zero() -> Result<()> {
+    assert_eq!(pow(Decimal::TEN, Decimal::from(-40))?, Decimal::ZERO);
+    assert_eq!(pow(Decimal::from(-10), Decimal::from(-41))?, Decimal::ZERO);
+    assert_eq!(pow(Decimal::new(5, 1), Decimal::from(200))?, Decimal::ZERO);
+    assert!(matches!(pow(Decimal::new(5, 1), Decimal::from(-200)), Err(Error::Calculation(_))));
+    Ok(())
+}
+
+#[test]
+fn test_horner() -> Result<()> {
+    // 1 + 2x + 3x² at x = 2.
+    let coefficients = [Decimal::ONE, Decimal::TWO, Decimal::from(3)];
+    assert_eq!(horner(&coefficients, Decimal::TWO)?, Decimal::from(17));
+    assert_eq!(horner(&[], Decimal::TWO)?, Decimal::ZERO);
+    assert!(horner(&[Decimal::ONE; 4], Decimal::MAX).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_interpolate() -> Result<()> {
+    let table = [(Decimal::ONE, Decimal::TEN), (Decimal::from(3), Decimal::from(20))];
+    assert_eq!(interpolate(&table, Decimal::TWO)?, Decimal::from(15));
+    assert_eq!(interpolate(&table, Decimal::ZERO)?, Decimal::TEN);
+    assert_eq!(interpolate(&table, Decimal::from(9))?, Decimal::from(20));
+    assert!(interpolate(&[], Decimal::ONE).is_err());
+    let unsorted = [table[1], table[0]];
+    assert!(matches!(interpolate(&unsorted, Decimal::ONE), Err(Error::Calculation(_))));
+    Ok(())
+}
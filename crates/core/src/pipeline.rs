@@ -0,0 +1,123 @@
+//! Calculation pipelines: wire named outputs of one calculator into the next.
+
+use crate::{find_handle, CalcInput, CalcResult, CalculatorHandle, Error, PipelineStep, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Ordered chain of calculator steps, typically loaded from TOML.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pipeline {
+    #[serde(default)]
+    pub name: String,
+    pub steps: Vec<PipelineStep>,
+}
+
+/// Every intermediate result of a pipeline run, in step order.
+#[derive(Debug, Clone, Default)]
+pub struct PipelineRun {
+    pub steps: Vec<(String, CalcResult)>,
+    /// Warnings from all steps, prefixed with the step name.
+    pub warnings: Vec<String>,
+}
+
+impl PipelineRun {
+    pub fn get(&self, step: &str) -> Option<&CalcResult> {
+        self.steps.iter().find(|(name, _)| name == step).map(|(_, result)| result)
+    }
+
+    /// Result of the last step.
+    pub fn output(&self) -> Option<&CalcResult> {
+        self.steps.last().map(|(_, result)| result)
+    }
+
+    fn resolve(&self, source: &str) -> Result<String> {
+        let (step, label) = match source.split_once('.') {
+            Some((step, label)) => (step, Some(label)),
+            None => (source, None),
+        };
+        let result = self.get(step).ok_or_else(|| Error::Config(format!("No step '{}'", step)))?;
+        let Some(label) = label else { return Ok(result.output.value.to_string()) };
+        let secondary = result.secondary.iter().find(|s| s.label == label).map(|s| s.value);
+        let meta = result.metadata.iter().find(|(k, _)| k == label).map(|(_, v)| v.clone());
+        secondary.map(|v| v.to_string()).or(meta).ok_or_else(|| {
+            Error::MissingInput(format!("Step '{}' has no output '{}'", step, label))
+        })
+    }
+}
+
+impl Pipeline {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), steps: Vec::new() }
+    }
+
+    pub fn then(mut self, step: PipelineStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Run against the compiled and user calculators.
+    pub fn run(&self) -> Result<PipelineRun> {
+        self.run_with(find_handle)
+    }
+
+    /// Run with a custom calculator lookup. Calculators, step names and input
+    /// sources are all checked before the first step runs.
+    pub fn run_with(
+        &self,
+        lookup: impl Fn(&str) -> Option<CalculatorHandle>,
+    ) -> Result<PipelineRun> {
+        let calculators = self.check(lookup)?;
+        let mut run = PipelineRun::default();
+        for (step, calculator) in self.steps.iter().zip(calculators) {
+            let name = step.step_name();
+            let mut input = CalcInput::new();
+            input.measurements.extend(step.measurements.iter().copied());
+            for (k, v) in &step.params {
+                input = input.set_param(k.as_str(), v.as_str());
+            }
+            for (param, source) in &step.inputs {
+                input = input.set_param(param.as_str(), run.resolve(source)?);
+            }
+            let result = calculator.calculate(input).map_err(|e| {
+                Error::Calculation(format!("Step '{}' ({}): {}", name, step.calculator, e))
+            })?;
+            run.warnings.extend(result.warnings.iter().map(|w| format!("{}: {}", name, w)));
+            run.steps.push((name.to_string(), result));
+        }
+        Ok(run)
+    }
+
+    /// Resolve every calculator and confirm each input names an earlier step.
+    fn check(
+        &self,
+        lookup: impl Fn(&str) -> Option<CalculatorHandle>,
+    ) -> Result<Vec<CalculatorHandle>> {
+        if self.steps.is_empty() {
+            return Err(Error::Config(format!("Pipeline '{}' has no steps", self.name)));
+        }
+        let mut seen = HashSet::new();
+        let mut calculators = Vec::with_capacity(self.steps.len());
+        for step in &self.steps {
+            let name = step.step_name();
+            for source in step.inputs.values() {
+                let from = source.split_once('.').map_or(source.as_str(), |(from, _)| from);
+                if !seen.contains(from) {
+                    let msg = format!("Step '{}' reads '{}' before it runs", name, source);
+                    return Err(Error::Config(msg));
+                }
+            }
+            if name.contains('.') || !seen.insert(name) {
+                return Err(Error::Config(format!("Invalid or duplicate step name '{}'", name)));
+            }
+            let calculator = lookup(&step.calculator).ok_or_else(|| {
+                Error::Config(format!("Unknown calculator '{}'", step.calculator))
+            })?;
+            calculators.push(calculator);
+        }
+        Ok(calculators)
+    }
+}
+
+#[cfg(test)]
+#[path = "pipeline_tests.rs"]
+mod tests;
@@ -0,0 +1,59 @@
+//! A single step of a calculation pipeline.
+
+use crate::Measurement;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One calculator run within a pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PipelineStep {
+    /// Name later steps refer to; defaults to the calculator ID.
+    #[serde(default)]
+    pub name: Option<String>,
+    pub calculator: String,
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    #[serde(default)]
+    pub measurements: Vec<Measurement>,
+    /// Parameters taken from earlier steps: `"step"` is that step's primary
+    /// output, `"step.Label"` a secondary value or metadata entry.
+    #[serde(default)]
+    pub inputs: BTreeMap<String, String>,
+}
+
+impl PipelineStep {
+    pub fn new(calculator: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            calculator: calculator.into(),
+            params: BTreeMap::new(),
+            measurements: Vec::new(),
+            inputs: BTreeMap::new(),
+        }
+    }
+
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn param(mut self, k: impl Into<String>, v: impl Into<String>) -> Self {
+        self.params.insert(k.into(), v.into());
+        self
+    }
+
+    pub fn measurement(mut self, m: Measurement) -> Self {
+        self.measurements.push(m);
+        self
+    }
+
+    /// Feed `param` from `source` (`"step"` or `"step.Label"`).
+    pub fn input(mut self, param: impl Into<String>, source: impl Into<String>) -> Self {
+        self.inputs.insert(param.into(), source.into());
+        self
+    }
+
+    pub fn step_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.calculator)
+    }
+}
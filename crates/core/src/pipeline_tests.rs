@@ -0,0 +1,85 @@
+use super::*;
+use crate::{Calculator, Measurement, Unit};
+
+/// Doubles `x`, reporting the input as a secondary and warning above 10.
+struct Double;
+
+impl Calculator for Double {
+    fn id(&self) -> &'static str {
+        "double"
+    }
+    fn name(&self) -> &'static str {
+        "Double"
+    }
+    fn description(&self) -> &'static str {
+        "2x"
+    }
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        let x = match input.get_param("x") {
+            Some(_) => input.get_decimal("x")?,
+            None => input.get_measurement(Unit::Liters)?.value,
+        };
+        let result = CalcResult::new(Measurement::new(x + x, Unit::Liters))
+            .with_secondary("Input", x, Unit::Liters)
+            .with_meta("input", x.to_string());
+        Ok(if x > 10.into() { result.with_warning("large") } else { result })
+    }
+}
+
+fn lookup(id: &str) -> Option<CalculatorHandle> {
+    (id == "double").then_some(CalculatorHandle::Compiled(&Double))
+}
+
+fn chain() -> Pipeline {
+    Pipeline::new("chain")
+        .then(PipelineStep::new("double").named("first").param("x", "6"))
+        .then(PipelineStep::new("double").named("second").input("x", "first"))
+        .then(PipelineStep::new("double").input("x", "first.Input"))
+}
+
+#[test]
+fn test_runs_steps_in_order() -> Result<()> {
+    let run = chain().run_with(lookup)?;
+    let names: Vec<_> = run.steps.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["first", "second", "double"]);
+    assert_eq!(run.get("second").map(|r| r.output.value), Some(24.into()));
+    assert_eq!(run.output().map(|r| r.output.value), Some(12.into()));
+    assert_eq!(run.warnings, vec!["second: large"]);
+    Ok(())
+}
+
+#[test]
+fn test_measurements_and_metadata_sources() -> Result<()> {
+    let litres = Measurement::new(5.into(), Unit::Liters);
+    let pipeline = Pipeline::new("m")
+        .then(PipelineStep::new("double").measurement(litres))
+        .then(PipelineStep::new("double").named("meta").input("x", "double.input"));
+    let run = pipeline.run_with(lookup)?;
+    assert_eq!(run.get("double").map(|r| r.output.value), Some(10.into()));
+    assert_eq!(run.output().map(|r| r.output.value), Some(10.into()));
+    Ok(())
+}
+
+#[test]
+fn test_step_errors_name_the_step() {
+    let step = PipelineStep::new("double").named("bad").param("x", "?");
+    let pipeline = Pipeline::new("e").then(step);
+    let err = pipeline.run_with(lookup);
+    assert!(matches!(err, Err(Error::Calculation(msg)) if msg.starts_with("Step 'bad' (double)")));
+}
+
+#[test]
+fn test_rejected_before_running() {
+    let forward = Pipeline::new("f").then(PipelineStep::new("double").input("x", "later"));
+    let duplicate = chain().then(PipelineStep::new("double").named("first"));
+    let unknown = chain().then(PipelineStep::new("missing"));
+    for pipeline in [forward, duplicate, unknown, Pipeline::new("empty")] {
+        assert!(matches!(pipeline.run_with(lookup), Err(Error::Config(_))));
+    }
+}
+
+#[test]
+fn test_missing_output_label() {
+    let pipeline = chain().then(PipelineStep::new("double").named("bad").input("x", "first.Nope"));
+    assert!(matches!(pipeline.run_with(lookup), Err(Error::MissingInput(_))));
+}
@@ -0,0 +1,117 @@
+//! Validated quantities for calculator internals.
+//!
+//! Each type checks its range when built and converts between the units it
+//! accepts, so passing a temperature where a gravity is expected, or °F where
+//! °C is expected, fails to compile instead of giving a wrong answer.
+
+use crate::{Error, Measurement, Result, Unit, Validator};
+use rust_decimal::Decimal;
+
+/// Quantity stored in a base unit and readable from measurements in any unit it accepts.
+pub trait Quantity: Sized + Copy {
+    /// Name used in error messages.
+    const NAME: &'static str;
+    /// Unit of bare parameters and of [`Quantity::base`].
+    const UNIT: Unit;
+
+    /// Build from `value` in the base unit, checking its range.
+    fn new(value: Decimal) -> Result<Self>;
+
+    /// Value in the base unit.
+    fn base(self) -> Decimal;
+
+    /// Whether measurements in `unit` can be read as this quantity.
+    fn accepts(unit: Unit) -> bool {
+        unit == Self::UNIT
+    }
+
+    /// Build from a measurement in any accepted unit.
+    fn from_measurement(m: Measurement) -> Result<Self> {
+        if m.unit != Self::UNIT {
+            return Err(Error::Validation(format!("{} is not a {}", m, Self::NAME)));
+        }
+        Self::new(m.value)
+    }
+
+    /// Measurement in the base unit.
+    fn measurement(self) -> Measurement {
+        Measurement::new(self.base(), Self::UNIT)
+    }
+}
+
+/// Specific gravity (0.6000–2.0000).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Sg(Decimal);
+
+/// Degrees Brix (0–70).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Brix(Decimal);
+
+/// pH (1.50–8.50).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Ph(Decimal);
+
+impl Quantity for Sg {
+    const NAME: &'static str = "specific gravity";
+    const UNIT: Unit = Unit::SpecificGravity;
+
+    fn new(value: Decimal) -> Result<Self> {
+        Validator::sg(value)?;
+        Ok(Self(value))
+    }
+
+    fn base(self) -> Decimal {
+        self.0
+    }
+}
+
+impl Quantity for Brix {
+    const NAME: &'static str = "Brix reading";
+    const UNIT: Unit = Unit::Brix;
+
+    fn new(value: Decimal) -> Result<Self> {
+        Validator::brix(value)?;
+        Ok(Self(value))
+    }
+
+    fn base(self) -> Decimal {
+        self.0
+    }
+}
+
+impl Quantity for Ph {
+    const NAME: &'static str = "pH";
+    const UNIT: Unit = Unit::Ph;
+
+    fn new(value: Decimal) -> Result<Self> {
+        Validator::ph(value)?;
+        Ok(Self(value))
+    }
+
+    fn base(self) -> Decimal {
+        self.0
+    }
+}
+
+impl Sg {
+    /// Gravity points above water, e.g. 50 for 1.050.
+    pub fn points(self) -> Decimal {
+        (self.0 - Decimal::ONE) * Decimal::from(1000)
+    }
+}
+
+impl Brix {
+    /// Linear approximation SG ≈ 1 + 0.004 × Brix.
+    pub fn to_sg(self) -> Result<Sg> {
+        Sg::new(Decimal::ONE + self.0 * Decimal::new(4, 3))
+    }
+
+    /// Warning above the typical 0–45 range.
+    pub fn warning(self) -> Option<String> {
+        Validator::brix_warning(self.0)
+    }
+}
+
+#[cfg(test)]
+#[path = "quantity_tests.rs"]
+mod tests;
@@ -0,0 +1,55 @@
+use super::*;
+use crate::{CalcInput, Temperature, Volume};
+
+#[test]
+fn test_checked_constructors() -> Result<()> {
+    assert_eq!(Sg::new(Decimal::new(1050, 3))?.points(), Decimal::from(50));
+    assert!(matches!(Sg::new(Decimal::from(3)), Err(Error::OutOfRange(_))));
+    assert!(matches!(Brix::new(Decimal::from(-1)), Err(Error::OutOfRange(_))));
+    assert!(matches!(Ph::new(Decimal::from(9)), Err(Error::OutOfRange(_))));
+    assert!(matches!(Volume::liters(Decimal::ZERO), Err(Error::Validation(_))));
+    assert!(matches!(Temperature::fahrenheit(Decimal::from(300)), Err(Error::OutOfRange(_))));
+    Ok(())
+}
+
+#[test]
+fn test_conversions() -> Result<()> {
+    assert_eq!(Brix::new(Decimal::from(25))?.to_sg()?.base(), Decimal::new(11, 1));
+    assert!(Brix::new(Decimal::from(50))?.warning().is_some());
+
+    let temp = Temperature::fahrenheit(Decimal::from(68))?;
+    assert_eq!(temp.as_celsius(), Decimal::from(20));
+    assert_eq!(Temperature::celsius(Decimal::from(20))?.as_fahrenheit(), Decimal::from(68));
+
+    let volume = Volume::gallons(Decimal::ONE)?;
+    assert_eq!(volume.as_liters(), Decimal::new(3785411784, 9));
+    assert_eq!(volume.as_gallons(), Decimal::ONE);
+    assert_eq!(Volume::milliliters(Decimal::from(750))?.as_liters(), Decimal::new(75, 2));
+    assert_eq!(Volume::liters(Decimal::from(20))?.as_hectoliters(), Decimal::new(2, 1));
+    Ok(())
+}
+
+#[test]
+fn test_measurements_convert_by_unit() -> Result<()> {
+    let input = CalcInput::new()
+        .add_measurement(Measurement::new(Decimal::from(50), Unit::Fahrenheit))
+        .add_measurement(Measurement::new(Decimal::from(500), Unit::Milliliters));
+    assert_eq!(input.get_measured::<Temperature>()?.as_celsius(), Decimal::from(10));
+    assert_eq!(input.get_measured::<Volume>()?.as_liters(), Decimal::new(5, 1));
+    assert!(matches!(input.get_measured::<Sg>(), Err(Error::MissingInput(_))));
+
+    let wrong = Measurement::new(Decimal::from(20), Unit::Brix);
+    assert!(matches!(Temperature::from_measurement(wrong), Err(Error::Validation(_))));
+    let sg = Measurement::new(Decimal::ONE, Unit::SpecificGravity);
+    assert_eq!(Sg::new(Decimal::ONE)?.measurement(), sg);
+    Ok(())
+}
+
+#[test]
+fn test_parameters_use_base_unit() -> Result<()> {
+    let input = CalcInput::new().add_param("temp", "4").add_param("volume", "-1");
+    assert_eq!(input.get_quantity::<Temperature>("temp")?.as_fahrenheit(), Decimal::new(392, 1));
+    assert!(matches!(input.get_quantity::<Volume>("volume"), Err(Error::Validation(_))));
+    assert!(matches!(input.get_quantity::<Ph>("ph"), Err(Error::MissingInput(_))));
+    Ok(())
+}
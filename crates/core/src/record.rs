@@ -0,0 +1,144 @@
+//! Reproducible calculation records for logbooks.
+
+use crate::{find_handle, CalcInput, CalcResult, Calculator, Error, Measurement, Result};
+use serde::{Deserialize, Serialize};
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// A stored calculation: which formula version ran, on what input, giving what.
+///
+/// `hash` covers every other field, so edited or corrupted records are caught
+/// before they are replayed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalculationRecord {
+    pub calculator_id: String,
+    pub version: u32,
+    pub input: CalcInput,
+    pub result: CalcResult,
+    pub hash: String,
+}
+
+/// Outcome of re-running a [`CalculationRecord`] with the current calculator.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    pub recorded_version: u32,
+    pub current_version: u32,
+    pub result: CalcResult,
+    /// Changes to the output and secondary values, e.g. `"Output: 1.08 SG → 1.079 SG"`.
+    pub differences: Vec<String>,
+}
+
+impl Replay {
+    /// Whether the current calculator gives the stored output and secondary values.
+    pub fn reproduced(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Whether the formula version changed since the record was made.
+    pub fn version_changed(&self) -> bool {
+        self.recorded_version != self.current_version
+    }
+}
+
+impl CalculationRecord {
+    /// Record `result` as produced by `calculator` from `input`.
+    pub fn new(calculator: &dyn Calculator, input: CalcInput, result: CalcResult) -> Self {
+        let (calculator_id, version) = (calculator.id().to_string(), calculator.version());
+        let hash = content_hash(&calculator_id, version, &input, &result);
+        Self { calculator_id, version, input, result, hash }
+    }
+
+    /// Run `calculator` on `input` and record the result.
+    pub fn calculate(calculator: &dyn Calculator, input: CalcInput) -> Result<Self> {
+        let result = calculator.calculate(input.clone())?;
+        Ok(Self::new(calculator, input, result))
+    }
+
+    /// Whether `hash` still matches the record's contents.
+    pub fn verify(&self) -> bool {
+        self.hash == content_hash(&self.calculator_id, self.version, &self.input, &self.result)
+    }
+
+    /// Re-run with the compiled or user calculator, found by ID or alias.
+    pub fn replay(&self) -> Result<Replay> {
+        let calculator = find_handle(&self.calculator_id)
+            .ok_or_else(|| Error::Config(format!("Unknown calculator '{}'", self.calculator_id)))?;
+        self.replay_with(&*calculator)
+    }
+
+    /// Re-run with `calculator` and compare against the stored result.
+    pub fn replay_with(&self, calculator: &dyn Calculator) -> Result<Replay> {
+        if !self.verify() {
+            return Err(Error::Validation(format!(
+                "Record {} does not match its contents",
+                self.hash
+            )));
+        }
+        let result = calculator.calculate(self.input.clone())?;
+        let (stored, current) = (&self.result, &result);
+        let mut differences = Vec::new();
+        if current.output != stored.output {
+            differences.push(format!("Output: {} → {}", stored.output, current.output));
+        }
+        for old in &stored.secondary {
+            match current.secondary.iter().find(|new| new.label == old.label) {
+                Some(new) if (new.value, new.unit) == (old.value, old.unit) => {}
+                Some(new) => differences.push(format!(
+                    "{}: {} {} → {} {}",
+                    old.label, old.value, old.unit, new.value, new.unit
+                )),
+                None => differences.push(format!("{}: no longer reported", old.label)),
+            }
+        }
+        for new in &current.secondary {
+            if !stored.secondary.iter().any(|old| old.label == new.label) {
+                differences.push(format!("{}: newly reported", new.label));
+            }
+        }
+        Ok(Replay {
+            recorded_version: self.version,
+            current_version: calculator.version(),
+            result,
+            differences,
+        })
+    }
+}
+
+/// FNV-1a over a tagged text form of the record. Unlike `std`'s hasher it is
+/// stable across platforms and releases, so stored hashes stay valid; units are
+/// written by symbol rather than `Debug` output for the same reason.
+fn content_hash(id: &str, version: u32, input: &CalcInput, result: &CalcResult) -> String {
+    let measurement = |m: &Measurement| format!("{} {}", m.value, m.unit.symbol());
+    let mut text = format!("{}\x1f{}", id, version);
+    let mut field = |tag: &str, value: String| text += &format!("\x1f{}:{}", tag, value);
+    for m in &input.measurements {
+        field("in", measurement(m));
+    }
+    for (key, value) in &input.params {
+        field("param", format!("{}={}", key, value));
+    }
+    field("out", measurement(&result.output));
+    for s in &result.secondary {
+        field("secondary", format!("{}={} {}", s.label, s.value, s.unit.symbol()));
+    }
+    for warning in &result.warnings {
+        field("warning", warning.clone());
+    }
+    for note in &result.notes {
+        field("note", note.clone());
+    }
+    for s in &result.trace {
+        let step = [s.label.as_str(), &s.expression, &s.substituted, &s.value.to_string()];
+        field("step", step.join("\x1e"));
+    }
+    for (key, value) in &result.metadata {
+        field("meta", format!("{}={}", key, value));
+    }
+    let hash = text.bytes().fold(FNV_OFFSET, |h, b| (h ^ u64::from(b)).wrapping_mul(FNV_PRIME));
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+#[path = "record_tests.rs"]
+mod tests;
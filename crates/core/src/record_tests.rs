@@ -0,0 +1,75 @@
+use super::*;
+use crate::Unit;
+use rust_decimal::Decimal;
+
+/// Multiplies `x` by `factor`, reporting `x` as a secondary value.
+struct Scale {
+    factor: i64,
+    version: u32,
+}
+
+impl Calculator for Scale {
+    fn id(&self) -> &'static str {
+        "scale"
+    }
+    fn name(&self) -> &'static str {
+        "Scale"
+    }
+    fn description(&self) -> &'static str {
+        "x times a factor"
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        let x = input.get_decimal("x")?;
+        Ok(CalcResult::new(Measurement::new(x * Decimal::from(self.factor), Unit::Liters))
+            .with_secondary("Input", x, Unit::Liters))
+    }
+}
+
+fn sample() -> Result<CalculationRecord> {
+    let calc = Scale { factor: 2, version: 1 };
+    CalculationRecord::calculate(&calc, CalcInput::new().add_param("x", "1.5"))
+}
+
+#[test]
+fn test_record_hash_is_stable() -> Result<()> {
+    let record = sample()?;
+    assert_eq!((record.calculator_id.as_str(), record.version), ("scale", 1));
+    assert_eq!(record.result.output.value, Decimal::new(30, 1));
+    assert_eq!(record.hash, "ca557aed5140994c");
+    assert!(record.verify());
+    assert_eq!(record.hash, sample()?.hash);
+    Ok(())
+}
+
+#[test]
+fn test_replay_reproduces_same_version() -> Result<()> {
+    let replay = sample()?.replay_with(&Scale { factor: 2, version: 1 })?;
+    assert!(replay.reproduced());
+    assert!(!replay.version_changed());
+    assert_eq!(replay.result.output.value, Decimal::new(30, 1));
+    Ok(())
+}
+
+#[test]
+fn test_replay_reports_changed_formula() -> Result<()> {
+    let replay = sample()?.replay_with(&Scale { factor: 3, version: 2 })?;
+    assert!(!replay.reproduced());
+    assert!(replay.version_changed());
+    assert_eq!(replay.differences, vec!["Output: 3.0 L → 4.5 L".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn test_edited_record_is_rejected() -> Result<()> {
+    let mut edited = sample()?;
+    edited.result.output.value = Decimal::from(4);
+    assert!(!edited.verify());
+    let replay = edited.replay_with(&Scale { factor: 2, version: 1 });
+    assert!(matches!(replay, Err(Error::Validation(_))));
+    edited.result.output.value = Decimal::new(30, 1);
+    assert!(edited.verify());
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! Compile-time calculator registry with ID aliases and duplicate detection.
+
+use crate::{Calculator, Error, Result};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Calculator registry entry.
+pub struct CalculatorEntry {
+    pub id: &'static str,
+    pub instance: fn() -> &'static dyn Calculator,
+}
+
+impl CalculatorEntry {
+    pub const fn new(id: &'static str, instance: fn() -> &'static dyn Calculator) -> Self {
+        Self { id, instance }
+    }
+}
+
+/// Inventory of all calculators.
+#[linkme::distributed_slice]
+pub static CALCULATORS: [CalculatorEntry];
+
+/// Lookup tables built once from [`CALCULATORS`].
+struct Registry {
+    calculators: Vec<&'static dyn Calculator>,
+    index: HashMap<&'static str, &'static dyn Calculator>,
+    conflicts: Vec<String>,
+}
+
+static REGISTRY: OnceLock<Registry> = OnceLock::new();
+
+fn registry() -> &'static Registry {
+    REGISTRY.get_or_init(|| build(CALCULATORS.iter().map(|e| (e.instance)())))
+}
+
+/// Display order: category, then sort order, then name.
+pub(crate) fn by_order(a: &dyn Calculator, b: &dyn Calculator) -> std::cmp::Ordering {
+    (a.category(), a.sort_order(), a.name()).cmp(&(b.category(), b.sort_order(), b.name()))
+}
+
+/// Index calculators by ID, then by alias. The first registration of a name wins
+/// and every later claim on it is recorded as a conflict.
+fn build(calcs: impl Iterator<Item = &'static dyn Calculator>) -> Registry {
+    let mut calculators: Vec<_> = calcs.collect();
+    calculators.sort_by(|a, b| by_order(*a, *b));
+
+    let mut index: HashMap<&'static str, &'static dyn Calculator> = HashMap::new();
+    let mut conflicts = Vec::new();
+    let names = calculators.iter().map(|c| (c.id(), *c, "ID"));
+    let aliases = calculators
+        .iter()
+        .flat_map(|c| c.aliases().iter().map(move |alias| (*alias, *c, "alias")));
+    for (name, calc, kind) in names.chain(aliases) {
+        match index.get(name) {
+            Some(existing) => conflicts.push(format!(
+                "{} '{}' of '{}' is already registered by '{}'",
+                kind,
+                name,
+                calc.name(),
+                existing.name()
+            )),
+            None => {
+                index.insert(name, calc);
+            }
+        }
+    }
+
+    Registry { calculators, index, conflicts }
+}
+
+/// Check the registry, failing with every duplicate ID or alias found.
+///
+/// Call once at startup; lookups still work after a failure, resolving each
+/// duplicated name to its first registration.
+pub fn init() -> Result<()> {
+    let conflicts = &registry().conflicts;
+    if conflicts.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Config(format!("Duplicate calculator names: {}", conflicts.join("; "))))
+    }
+}
+
+/// All compiled calculators, ordered by category, sort order and name.
+///
+/// User formulas are listed by [`calculator_handles`](crate::calculator_handles).
+pub fn get_all_calculators() -> &'static [&'static dyn Calculator] {
+    &registry().calculators
+}
+
+/// Find a compiled calculator by ID or alias.
+///
+/// [`find_handle`](crate::find_handle) also finds user formulas.
+pub fn find_calculator(id: &str) -> Option<&'static dyn Calculator> {
+    registry().index.get(id).copied()
+}
+
+/// Get calculator by ID or alias; same as [`find_calculator`].
+pub fn get_calculator(id: &str) -> Option<&'static dyn Calculator> {
+    find_calculator(id)
+}
+
+/// List all compiled calculator IDs.
+pub fn list_calculators() -> Vec<&'static str> {
+    get_all_calculators().iter().map(|c| c.id()).collect()
+}
+
+/// Macro to register a calculator.
+#[macro_export]
+macro_rules! register_calculator {
+    ($calc:ty) => {
+        #[::linkme::distributed_slice($crate::registry::CALCULATORS)]
+        static ENTRY: $crate::registry::CalculatorEntry =
+            $crate::registry::CalculatorEntry::new(<$calc>::ID, || {
+                static INSTANCE: ::std::sync::OnceLock<$calc> = ::std::sync::OnceLock::new();
+                INSTANCE.get_or_init(<$calc>::default)
+            });
+    };
+}
+
+#[cfg(test)]
+#[path = "registry_tests.rs"]
+mod tests;
@@ -0,0 +1,81 @@
+use super::*;
+use crate::{CalcInput, CalcResult, Category, Measurement, Unit};
+
+struct Stub {
+    id: &'static str,
+    name: &'static str,
+    aliases: &'static [&'static str],
+    category: Category,
+}
+
+impl Calculator for Stub {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+    fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+    fn name(&self) -> &'static str {
+        self.name
+    }
+    fn description(&self) -> &'static str {
+        "Test stub"
+    }
+    fn category(&self) -> Category {
+        self.category
+    }
+    fn calculate(&self, _input: CalcInput) -> Result<CalcResult> {
+        Ok(CalcResult::new(Measurement::new(Default::default(), Unit::Grams)))
+    }
+}
+
+static COLOUR: Stub = Stub {
+    id: "colour",
+    name: "Colour",
+    aliases: &["color"],
+    category: Category::Brewing,
+};
+static ABV: Stub = Stub { id: "abv", name: "ABV", aliases: &[], category: Category::Basic };
+static ABV_COPY: Stub = Stub { id: "abv", name: "ABV copy", aliases: &[], category: Category::Basic };
+static SRM: Stub = Stub { id: "srm", name: "SRM", aliases: &["color"], category: Category::Brewing };
+
+fn registry_of(calcs: &[&'static Stub]) -> Registry {
+    build(calcs.iter().map(|c| *c as &dyn Calculator))
+}
+
+#[test]
+fn test_sorted_by_category() {
+    let registry = registry_of(&[&COLOUR, &ABV]);
+    let ids: Vec<_> = registry.calculators.iter().map(|c| c.id()).collect();
+    assert_eq!(ids, ["abv", "colour"]);
+    assert!(registry.conflicts.is_empty());
+}
+
+#[test]
+fn test_alias_resolves_to_calculator() {
+    let registry = registry_of(&[&COLOUR, &ABV]);
+    assert_eq!(registry.index.get("color").map(|c| c.id()), Some("colour"));
+    assert_eq!(registry.index.get("colour").map(|c| c.id()), Some("colour"));
+    assert!(!registry.index.contains_key("missing"));
+}
+
+#[test]
+fn test_duplicate_id_reported() {
+    let registry = registry_of(&[&ABV, &ABV_COPY]);
+    assert_eq!(registry.calculators.len(), 2);
+    assert_eq!(registry.conflicts.len(), 1);
+    assert!(registry.conflicts[0].contains("ID 'abv'"));
+}
+
+#[test]
+fn test_duplicate_alias_reported() {
+    let registry = registry_of(&[&COLOUR, &SRM]);
+    assert_eq!(registry.conflicts.len(), 1);
+    assert!(registry.conflicts[0].contains("alias 'color'"));
+}
+
+#[test]
+fn test_empty_registry_is_valid() {
+    assert!(init().is_ok());
+    assert!(find_calculator("abv").is_none());
+}
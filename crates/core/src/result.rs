@@ -0,0 +1,114 @@
+//! Calculation results: primary output, secondary values, warnings and metadata.
+
+use crate::{Measurement, Unit};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Labelled secondary value reported alongside the primary output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecondaryResult {
+    pub label: String,
+    pub value: Decimal,
+    pub unit: Unit,
+}
+
+/// One line of working: what is computed, the formula, the formula with
+/// values substituted, and the intermediate result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceStep {
+    pub label: String,
+    pub expression: String,
+    pub substituted: String,
+    pub value: Decimal,
+}
+
+/// Calculation result with metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalcResult {
+    pub output: Measurement,
+    pub warnings: Vec<String>,
+    #[serde(default)]
+    pub secondary: Vec<SecondaryResult>,
+    #[serde(default)]
+    pub notes: Vec<String>,
+    /// Step-by-step working, in calculation order.
+    #[serde(default)]
+    pub trace: Vec<TraceStep>,
+    pub metadata: Vec<(String, String)>,
+}
+
+impl CalcResult {
+    pub fn new(output: Measurement) -> Self {
+        Self {
+            output,
+            warnings: Vec::new(),
+            secondary: Vec::new(),
+            notes: Vec::new(),
+            trace: Vec::new(),
+            metadata: Vec::new(),
+        }
+    }
+
+    pub fn with_warning(mut self, msg: impl Into<String>) -> Self {
+        self.warnings.push(msg.into());
+        self
+    }
+
+    /// Attach a labelled secondary value (table rows, derived quantities).
+    pub fn with_secondary(mut self, label: impl Into<String>, value: Decimal, unit: Unit) -> Self {
+        self.secondary.push(SecondaryResult {
+            label: label.into(),
+            value,
+            unit,
+        });
+        self
+    }
+
+    /// Attach an informational note (advice rather than a warning).
+    pub fn with_note(mut self, msg: impl Into<String>) -> Self {
+        self.notes.push(msg.into());
+        self
+    }
+
+    pub fn with_meta(mut self, key: impl Into<String>, val: impl Into<String>) -> Self {
+        self.metadata.push((key.into(), val.into()));
+        self
+    }
+
+    /// Append a step of working, e.g. `("ABV", "(OG − FG) × 131.25", "(1.1 − 1.0) × 131.25", v)`.
+    pub fn with_step(
+        mut self,
+        label: impl Into<String>,
+        expression: impl Into<String>,
+        substituted: impl Into<String>,
+        value: Decimal,
+    ) -> Self {
+        self.trace.push(TraceStep {
+            label: label.into(),
+            expression: expression.into(),
+            substituted: substituted.into(),
+            value,
+        });
+        self
+    }
+
+    /// Plain-text working: numbered steps, then the result, warnings and notes.
+    pub fn explain(&self) -> String {
+        let mut text = String::new();
+        for (n, step) in self.trace.iter().enumerate() {
+            text += &format!("{}. {} = {}\n", n + 1, step.label, step.expression);
+            text += &format!("   = {}\n   = {}\n", step.substituted, step.value.normalize());
+        }
+        text += &format!("Result: {}\n", self.output);
+        for secondary in &self.secondary {
+            text += &format!("  {}: {} {}\n", secondary.label, secondary.value, secondary.unit);
+        }
+        for warning in &self.warnings {
+            text += &format!("Warning: {}\n", warning);
+        }
+        for note in &self.notes {
+            text += &format!("Note: {}\n", note);
+        }
+        text
+    }
+}
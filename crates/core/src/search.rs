@@ -0,0 +1,108 @@
+//! Category listing and fuzzy search over registered calculators.
+
+use crate::user_calculators::calculator_handles;
+use crate::{Calculator, CalculatorHandle, Category};
+
+/// Compiled and user calculators in `category`, ordered by sort order then name.
+pub fn list_by_category(category: Category) -> Vec<CalculatorHandle> {
+    in_category(&calculator_handles(), category)
+}
+
+/// Search compiled and user calculators by id, name, description, tags and keywords.
+///
+/// Every whitespace-separated term must match somewhere; terms may be prefixes,
+/// substrings, one typo away from a word, or a subsequence of the name.
+/// Results are ranked best match first.
+pub fn search_calculators(query: &str) -> Vec<CalculatorHandle> {
+    rank(&calculator_handles(), query)
+}
+
+fn by_order(a: &CalculatorHandle, b: &CalculatorHandle) -> std::cmp::Ordering {
+    (a.sort_order(), a.name()).cmp(&(b.sort_order(), b.name()))
+}
+
+fn in_category(calcs: &[CalculatorHandle], category: Category) -> Vec<CalculatorHandle> {
+    let mut calcs: Vec<_> = calcs.iter().filter(|c| c.category() == category).cloned().collect();
+    calcs.sort_by(by_order);
+    calcs
+}
+
+fn rank(calcs: &[CalculatorHandle], query: &str) -> Vec<CalculatorHandle> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits: Vec<(u32, &CalculatorHandle)> = calcs
+        .iter()
+        .filter_map(|calc| {
+            let mut total = 0;
+            for term in &terms {
+                total += match score(&**calc, term) {
+                    0 => return None,
+                    s => s,
+                };
+            }
+            Some((total, calc))
+        })
+        .collect();
+    hits.sort_by(|(sa, a), (sb, b)| sb.cmp(sa).then(by_order(a, b)));
+    hits.into_iter().map(|(_, calc)| calc.clone()).collect()
+}
+
+/// Relevance of a single lowercase search term for a calculator (0 = no match).
+fn score(calc: &dyn Calculator, term: &str) -> u32 {
+    let id = calc.id();
+    let name = calc.name().to_lowercase();
+    let tags = calc.tags();
+    let labels = tags.iter().chain(calc.keywords());
+    let description = calc.description().to_lowercase();
+    let words = || {
+        name.split(|c: char| !c.is_alphanumeric())
+            .chain(id.split('_'))
+            .chain(description.split(|c: char| !c.is_alphanumeric()))
+            .filter(|w| !w.is_empty())
+    };
+
+    if id == term || name == term {
+        100
+    } else if name.starts_with(term) || id.starts_with(term) {
+        80
+    } else if labels.clone().any(|t| t.eq_ignore_ascii_case(term)) {
+        70
+    } else if name.contains(term) || id.contains(term) {
+        60
+    } else if labels.clone().any(|t| t.to_lowercase().contains(term)) {
+        50
+    } else if description.contains(term) {
+        30
+    } else if term.chars().count() >= 4 && words().any(|w| within_one_edit(w, term)) {
+        20
+    } else if is_subsequence(term, &name) {
+        10
+    } else {
+        0
+    }
+}
+
+/// True when `a` and `b` differ by at most one insertion, deletion or substitution.
+fn within_one_edit(a: &str, b: &str) -> bool {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let (short, long) = if a.len() <= b.len() { (&a, &b) } else { (&b, &a) };
+    if long.len() - short.len() > 1 {
+        return false;
+    }
+    let prefix = short.iter().zip(long.iter()).take_while(|(x, y)| x == y).count();
+    let skip = if short.len() == long.len() { 1 } else { 0 };
+    short.get(prefix + skip..).unwrap_or_default() == long.get(prefix + 1..).unwrap_or_default()
+}
+
+/// True when all characters of `needle` appear in order in `haystack`.
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut chars = haystack.chars();
+    needle.chars().all(|n| chars.any(|h| h == n))
+}
+
+#[cfg(test)]
+#[path = "search_tests.rs"]
+mod tests;
@@ -0,0 +1,104 @@
+use super::*;
+use crate::{CalcInput, CalcResult, Measurement, Result, Unit};
+
+struct SulfiteStub;
+
+impl Calculator for SulfiteStub {
+    fn id(&self) -> &'static str {
+        "test_sulfite"
+    }
+    fn name(&self) -> &'static str {
+        "Sulfite Addition"
+    }
+    fn description(&self) -> &'static str {
+        "Potassium metabisulfite dose for free SO2"
+    }
+    fn category(&self) -> Category {
+        Category::Finishing
+    }
+    fn tags(&self) -> Vec<&str> {
+        vec!["stabilizing"]
+    }
+    fn keywords(&self) -> &'static [&'static str] {
+        &["kmeta", "campden"]
+    }
+    fn sort_order(&self) -> u32 {
+        20
+    }
+    fn calculate(&self, _input: CalcInput) -> Result<CalcResult> {
+        Ok(CalcResult::new(Measurement::new(Default::default(), Unit::Grams)))
+    }
+}
+
+struct SorbateStub;
+
+impl Calculator for SorbateStub {
+    fn id(&self) -> &'static str {
+        "test_sorbate"
+    }
+    fn name(&self) -> &'static str {
+        "Sorbate Addition"
+    }
+    fn description(&self) -> &'static str {
+        "Potassium sorbate dose by pH and ABV"
+    }
+    fn category(&self) -> Category {
+        Category::Finishing
+    }
+    fn tags(&self) -> Vec<&str> {
+        vec!["stabilizing"]
+    }
+    fn sort_order(&self) -> u32 {
+        10
+    }
+    fn calculate(&self, _input: CalcInput) -> Result<CalcResult> {
+        Ok(CalcResult::new(Measurement::new(Default::default(), Unit::Grams)))
+    }
+}
+
+fn stubs() -> Vec<CalculatorHandle> {
+    vec![CalculatorHandle::Compiled(&SulfiteStub), CalculatorHandle::Compiled(&SorbateStub)]
+}
+
+fn ids(calcs: Vec<CalculatorHandle>) -> Vec<String> {
+    calcs.iter().map(|c| c.id().to_string()).collect()
+}
+
+fn search(query: &str) -> Vec<String> {
+    ids(rank(&stubs(), query))
+}
+
+#[test]
+fn test_list_by_category_sorted() {
+    let finishing = ids(in_category(&stubs(), Category::Finishing));
+    assert_eq!(finishing, ["test_sorbate", "test_sulfite"]);
+    assert!(in_category(&stubs(), Category::Brewing).is_empty());
+}
+
+#[test]
+fn test_search_by_keyword_and_tag() {
+    assert_eq!(search("campden"), ["test_sulfite"]);
+    assert_eq!(search("stabilizing"), ["test_sorbate", "test_sulfite"]);
+}
+
+#[test]
+fn test_search_ranks_name_matches_first() {
+    assert_eq!(search("sulfite"), ["test_sulfite"]);
+    assert_eq!(search("potassium sorb"), ["test_sorbate"]);
+}
+
+#[test]
+fn test_search_tolerates_typos() {
+    assert_eq!(search("sulfate"), ["test_sulfite"]);
+    assert_eq!(search("srbt"), ["test_sorbate"]);
+    assert!(search("hydrometer").is_empty());
+    assert!(search("  ").is_empty());
+}
+
+#[test]
+fn test_within_one_edit() {
+    assert!(within_one_edit("sorbate", "sorbat"));
+    assert!(within_one_edit("sulfite", "sulfate"));
+    assert!(within_one_edit("abv", "abv"));
+    assert!(!within_one_edit("sorbate", "sulfite"));
+}
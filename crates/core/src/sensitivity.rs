@@ -0,0 +1,119 @@
+//! Sensitivity reports: the partial effect of each input on a calculator's output.
+
+use crate::{CalcInput, CalcResult, Calculator, Error, Result};
+use rust_decimal::Decimal;
+
+/// Effect of one input on the primary output around the base point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SensitivityEntry {
+    pub param: String,
+    /// Input value at the base point.
+    pub base: Decimal,
+    /// ∂output/∂param by finite difference.
+    pub derivative: Decimal,
+    /// Percent change in output per percent change in the input; `None` when
+    /// the input or the base output is zero.
+    pub elasticity: Option<Decimal>,
+}
+
+/// Base result plus one entry per perturbed input.
+#[derive(Debug, Clone)]
+pub struct SensitivityReport {
+    pub base: CalcResult,
+    pub entries: Vec<SensitivityEntry>,
+}
+
+impl SensitivityReport {
+    /// Entries ordered from most to least influential by absolute elasticity.
+    pub fn ranked(&self) -> Vec<&SensitivityEntry> {
+        let mut ranked: Vec<_> = self.entries.iter().collect();
+        ranked.sort_by_key(|e| std::cmp::Reverse(e.elasticity.unwrap_or_default().abs()));
+        ranked
+    }
+}
+
+/// Finite-difference sensitivity of a calculator around one input point.
+///
+/// Uses a central difference, or a one-sided one when a perturbed point fails
+/// validation (for example a pH nudged outside its allowed range).
+pub struct Sensitivity<'a> {
+    calculator: &'a dyn Calculator,
+    input: CalcInput,
+    params: Option<Vec<String>>,
+    step: Decimal,
+}
+
+impl<'a> Sensitivity<'a> {
+    /// Analyse every numeric parameter of `input`.
+    pub fn new(calculator: &'a dyn Calculator, input: CalcInput) -> Self {
+        Self { calculator, input, params: None, step: Decimal::new(1, 2) }
+    }
+
+    /// Only analyse the named parameters.
+    pub fn with_params<S: Into<String>>(mut self, params: impl IntoIterator<Item = S>) -> Self {
+        self.params = Some(params.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Relative perturbation (default 0.01, i.e. ±1%); used as an absolute
+    /// step for inputs that are zero.
+    pub fn with_step(mut self, step: Decimal) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    fn output(&self, param: &str, value: Option<Decimal>) -> Result<Decimal> {
+        let value = value.ok_or_else(|| Error::Calculation(format!("{} overflows", param)))?;
+        let input = self.input.clone().set_param(param, value.to_string());
+        Ok(self.calculator.calculate(input)?.output.value)
+    }
+
+    /// Run the base calculation and perturb each parameter in turn.
+    pub fn report(&self) -> Result<SensitivityReport> {
+        if self.step.is_zero() {
+            return Err(Error::Validation("Sensitivity step must be positive".into()));
+        }
+        let base = self.calculator.calculate(self.input.clone())?;
+        let y = base.output.value;
+        let params = match &self.params {
+            Some(params) => params.clone(),
+            None => (self.input.params.iter())
+                .filter(|(_, v)| v.trim().parse::<Decimal>().is_ok())
+                .map(|(k, _)| k.clone())
+                .collect(),
+        };
+
+        let mut entries = Vec::with_capacity(params.len());
+        for param in params {
+            let x = self.input.get_decimal(&param)?;
+            let h = match x.is_zero() {
+                true => Some(self.step),
+                false => x.checked_mul(self.step).map(|h| h.abs()),
+            };
+            let h = h.filter(|h| !h.is_zero()).ok_or_else(|| {
+                Error::Validation(format!("{} perturbation is zero or overflows", param))
+            })?;
+            let slope = |rise: Option<Decimal>, run| rise.and_then(|rise| rise.checked_div(run));
+            let up = self.output(&param, x.checked_add(h));
+            let derivative = match (up, self.output(&param, x.checked_sub(h))) {
+                (Ok(up), Ok(down)) => slope(up.checked_sub(down), h).map(|d| d / Decimal::TWO),
+                (Ok(up), Err(_)) => slope(up.checked_sub(y), h),
+                (Err(_), Ok(down)) => slope(y.checked_sub(down), h),
+                (Err(e), Err(_)) => return Err(e),
+            };
+            let derivative = derivative.ok_or_else(|| {
+                Error::Calculation(format!("{} derivative overflows", param))
+            })?;
+            let elasticity = match x.is_zero() || y.is_zero() {
+                true => None,
+                false => derivative.checked_mul(x).and_then(|d| d.checked_div(y)),
+            };
+            entries.push(SensitivityEntry { param, base: x, derivative, elasticity });
+        }
+        Ok(SensitivityReport { base, entries })
+    }
+}
+
+#[cfg(test)]
+#[path = "sensitivity_tests.rs"]
+mod tests;
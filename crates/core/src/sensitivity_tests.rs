@@ -0,0 +1,81 @@
+use super::*;
+use crate::{Measurement, Unit};
+
+/// y = a² · b, rejecting a above 10.
+struct Product;
+
+impl Calculator for Product {
+    fn id(&self) -> &'static str {
+        "product"
+    }
+    fn name(&self) -> &'static str {
+        "Product"
+    }
+    fn description(&self) -> &'static str {
+        "a² · b"
+    }
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        let a = input.get_decimal("a")?;
+        let b = input.get_decimal("b")?;
+        if a > Decimal::TEN {
+            return Err(Error::OutOfRange("a above 10".into()));
+        }
+        Ok(CalcResult::new(Measurement::new(a * a * b, Unit::Percent)))
+    }
+}
+
+fn input(a: &str, b: &str) -> CalcInput {
+    CalcInput::new().add_param("a", a).add_param("b", b).add_param("mode", "x")
+}
+
+fn entry<'r>(report: &'r SensitivityReport, param: &str) -> Option<&'r SensitivityEntry> {
+    report.entries.iter().find(|e| e.param == param)
+}
+
+#[test]
+fn test_partial_derivatives_and_elasticities() -> Result<()> {
+    let report = Sensitivity::new(&Product, input("3", "2")).report()?;
+    assert_eq!(report.base.output.value, Decimal::from(18));
+    assert_eq!(report.entries.len(), 2);
+    let a = entry(&report, "a").ok_or(Error::Calculation("missing a".into()))?;
+    let b = entry(&report, "b").ok_or(Error::Calculation("missing b".into()))?;
+    assert_eq!(a.derivative, Decimal::from(12));
+    assert_eq!(b.derivative, Decimal::from(9));
+    assert_eq!(a.elasticity, Some(Decimal::TWO));
+    assert_eq!(b.elasticity, Some(Decimal::ONE));
+    assert_eq!(report.ranked()[0].param, "a");
+    Ok(())
+}
+
+#[test]
+fn test_one_sided_difference_at_limit() -> Result<()> {
+    let report = Sensitivity::new(&Product, input("10", "1")).with_params(["a"]).report()?;
+    assert_eq!(report.entries.len(), 1);
+    // Backward difference of a² at 10 with h = 0.1: (100 − 98.01) / 0.1.
+    assert_eq!(report.entries[0].derivative, Decimal::new(199, 1));
+    Ok(())
+}
+
+#[test]
+fn test_zero_input_uses_absolute_step() -> Result<()> {
+    let report = Sensitivity::new(&Product, input("2", "0")).with_step(Decimal::ONE).report()?;
+    let b = entry(&report, "b").ok_or(Error::Calculation("missing b".into()))?;
+    assert_eq!(b.derivative, Decimal::from(4));
+    assert_eq!(b.elasticity, None);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_base_and_step() {
+    assert!(Sensitivity::new(&Product, input("11", "1")).report().is_err());
+    let zero = Sensitivity::new(&Product, input("1", "1")).with_step(Decimal::ZERO);
+    assert!(matches!(zero.report(), Err(Error::Validation(_))));
+}
+
+#[test]
+fn test_perturbation_rounding_to_zero() {
+    // 1e-28 × 0.01 is below Decimal's smallest step, so h would be zero.
+    let tiny = Sensitivity::new(&Product, input("0.0000000000000000000000000001", "1"));
+    let report = tiny.with_params(["a"]).report();
+    assert!(matches!(report, Err(Error::Validation(_))));
+}
@@ -0,0 +1,150 @@
+//! Parameter sweeps: run a calculator across a grid of one or two varying inputs.
+
+use crate::{CalcInput, CalcResult, Calculator, Error, Result};
+use rust_decimal::Decimal;
+
+/// Largest number of calculator runs a single sweep may request.
+pub const MAX_SWEEP_POINTS: usize = 10_000;
+
+/// Inclusive, evenly stepped range for one parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SweepRange {
+    pub param: String,
+    pub start: Decimal,
+    pub end: Decimal,
+    pub step: Decimal,
+}
+
+impl SweepRange {
+    pub fn new(param: impl Into<String>, start: Decimal, end: Decimal, step: Decimal) -> Self {
+        Self { param: param.into(), start, end, step }
+    }
+
+    /// Values from `start` to `end` inclusive; `end` is kept even if the step overshoots it.
+    pub fn values(&self) -> Result<Vec<Decimal>> {
+        if self.step <= Decimal::ZERO {
+            return Err(Error::Validation(format!("{} step must be positive", self.param)));
+        }
+        if self.start > self.end {
+            return Err(Error::Validation(format!("{} start must not exceed end", self.param)));
+        }
+        let count = self.end.checked_sub(self.start).and_then(|span| span.checked_div(self.step));
+        if count.is_none_or(|count| count >= Decimal::from(MAX_SWEEP_POINTS)) {
+            return Err(Error::Validation(format!("{} sweep has too many points", self.param)));
+        }
+        let mut values: Vec<Decimal> = Vec::new();
+        let mut next = Some(self.start);
+        while let Some(value) = next.filter(|value| *value <= self.end) {
+            values.push(value);
+            next = value.checked_add(self.step);
+        }
+        if values.last() != Some(&self.end) {
+            values.push(self.end);
+        }
+        Ok(values)
+    }
+}
+
+/// One grid cell: the swept values (x, then y) and the calculator outcome there.
+#[derive(Debug, Clone)]
+pub struct SweepPoint {
+    pub values: Vec<Decimal>,
+    pub outcome: Result<CalcResult>,
+}
+
+/// Sweep output laid out row-major: one row per x value, one column per y value.
+#[derive(Debug, Clone)]
+pub struct SweepGrid {
+    pub params: Vec<String>,
+    pub x: Vec<Decimal>,
+    pub y: Vec<Decimal>,
+    pub points: Vec<SweepPoint>,
+}
+
+impl SweepGrid {
+    /// Cell at x index `i` and y index `j` (`j` is 0 for one-parameter sweeps).
+    pub fn get(&self, i: usize, j: usize) -> Option<&SweepPoint> {
+        let width = self.y.len().max(1);
+        self.points.get(i * width + j).filter(|_| j < width)
+    }
+
+    /// Every warning and error in the grid, prefixed with the point it came from.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for point in &self.points {
+            let at = (self.params.iter().zip(&point.values))
+                .map(|(param, value)| format!("{}={}", param, value))
+                .collect::<Vec<_>>()
+                .join(", ");
+            match &point.outcome {
+                Ok(result) => {
+                    warnings.extend(result.warnings.iter().map(|w| format!("{}: {}", at, w)))
+                }
+                Err(e) => warnings.push(format!("{}: {}", at, e)),
+            }
+        }
+        warnings
+    }
+}
+
+/// Runs a calculator over one range, or the cross product of two.
+///
+/// A failing point is recorded in the grid rather than aborting the sweep, so
+/// out-of-range corners of a design space still leave the rest usable.
+pub struct Sweep<'a> {
+    calculator: &'a dyn Calculator,
+    input: CalcInput,
+    x: SweepRange,
+    y: Option<SweepRange>,
+}
+
+impl<'a> Sweep<'a> {
+    /// Sweep `x`, holding every other parameter of `input` fixed.
+    pub fn new(calculator: &'a dyn Calculator, input: CalcInput, x: SweepRange) -> Self {
+        Self { calculator, input, x, y: None }
+    }
+
+    /// Add a second swept parameter.
+    pub fn by(mut self, y: SweepRange) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Run every point of the grid.
+    pub fn run(&self) -> Result<SweepGrid> {
+        let x = self.x.values()?;
+        let y = match &self.y {
+            Some(range) if range.param == self.x.param => {
+                return Err(Error::Validation(format!("{} swept twice", range.param)));
+            }
+            Some(range) => range.values()?,
+            None => Vec::new(),
+        };
+        if x.len() * y.len().max(1) > MAX_SWEEP_POINTS {
+            return Err(Error::Validation("Sweep grid has too many points".into()));
+        }
+        let params = std::iter::once(&self.x).chain(&self.y).map(|r| r.param.clone()).collect();
+        let mut points = Vec::with_capacity(x.len() * y.len().max(1));
+        for xv in &x {
+            let input = self.input.clone().set_param(self.x.param.as_str(), xv.to_string());
+            match &self.y {
+                Some(range) => {
+                    for yv in &y {
+                        let cell = input.clone().set_param(range.param.as_str(), yv.to_string());
+                        let outcome = self.calculator.calculate(cell);
+                        points.push(SweepPoint { values: vec![*xv, *yv], outcome });
+                    }
+                }
+                None => {
+                    let outcome = self.calculator.calculate(input);
+                    points.push(SweepPoint { values: vec![*xv], outcome });
+                }
+            }
+        }
+        Ok(SweepGrid { params, x, y, points })
+    }
+}
+
+#[cfg(test)]
+#[path = "sweep_tests.rs"]
+mod tests;
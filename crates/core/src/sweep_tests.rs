@@ -0,0 +1,92 @@
+use super::*;
+use crate::{Measurement, Unit};
+
+/// y = x + 10·k, rejecting x above 5 and warning when negative.
+struct Plane;
+
+impl Calculator for Plane {
+    fn id(&self) -> &'static str {
+        "plane"
+    }
+    fn name(&self) -> &'static str {
+        "Plane"
+    }
+    fn description(&self) -> &'static str {
+        "x + 10k"
+    }
+    fn calculate(&self, input: CalcInput) -> Result<CalcResult> {
+        let x = input.get_decimal("x")?;
+        let k = input.get_decimal_or("k", Decimal::ZERO)?;
+        if x > Decimal::from(5) {
+            return Err(Error::OutOfRange("x above 5".into()));
+        }
+        let result = CalcResult::new(Measurement::new(x + Decimal::TEN * k, Unit::Percent));
+        Ok(if x.is_sign_negative() { result.with_warning("negative x") } else { result })
+    }
+}
+
+fn range(param: &str, start: i64, end: i64, step: i64) -> SweepRange {
+    SweepRange::new(param, Decimal::from(start), Decimal::from(end), Decimal::from(step))
+}
+
+fn output(grid: &SweepGrid, i: usize, j: usize) -> Option<Decimal> {
+    let point = grid.get(i, j)?;
+    point.outcome.as_ref().ok().map(|r| r.output.value)
+}
+
+#[test]
+fn test_range_values_include_end() -> Result<()> {
+    let fg = SweepRange::new("fg", Decimal::new(990, 3), Decimal::new(1030, 3), Decimal::new(2, 3));
+    let values = fg.values()?;
+    assert_eq!(values.len(), 21);
+    assert_eq!(values.last(), Some(&Decimal::new(1030, 3)));
+    assert_eq!(range("x", 0, 5, 2).values()?, [0, 2, 4, 5].map(Decimal::from).to_vec());
+    Ok(())
+}
+
+#[test]
+fn test_range_rejects_bad_steps() {
+    assert!(matches!(range("x", 0, 5, 0).values(), Err(Error::Validation(_))));
+    assert!(matches!(range("x", 5, 0, 1).values(), Err(Error::Validation(_))));
+    assert!(matches!(range("x", 0, 100_000, 1).values(), Err(Error::Validation(_))));
+    let huge = SweepRange::new("x", Decimal::MIN, Decimal::MAX, Decimal::ONE);
+    assert!(matches!(huge.values(), Err(Error::Validation(_))));
+}
+
+#[test]
+fn test_range_stops_at_decimal_max() -> Result<()> {
+    let top = SweepRange::new("x", Decimal::MAX - Decimal::TWO, Decimal::MAX, Decimal::TWO);
+    assert_eq!(top.values()?, vec![Decimal::MAX - Decimal::TWO, Decimal::MAX]);
+    Ok(())
+}
+
+#[test]
+fn test_one_parameter_sweep() -> Result<()> {
+    let input = CalcInput::new().add_param("k", "1");
+    let grid = Sweep::new(&Plane, input, range("x", -1, 6, 1)).run()?;
+    assert_eq!(grid.params, vec!["x".to_string()]);
+    assert_eq!(grid.points.len(), 8);
+    assert_eq!(output(&grid, 0, 0), Some(Decimal::from(9)));
+    assert_eq!(output(&grid, 6, 0), Some(Decimal::from(15)));
+    assert!(matches!(grid.get(7, 0).map(|p| &p.outcome), Some(Err(Error::OutOfRange(_)))));
+    assert!(grid.get(0, 1).is_none());
+    assert_eq!(grid.warnings(), vec!["x=-1: negative x", "x=6: Out of range: x above 5"]);
+    Ok(())
+}
+
+#[test]
+fn test_two_parameter_grid() -> Result<()> {
+    let sweep = Sweep::new(&Plane, CalcInput::new(), range("x", 0, 2, 1)).by(range("k", 0, 3, 1));
+    let grid = sweep.run()?;
+    assert_eq!((grid.x.len(), grid.y.len(), grid.points.len()), (3, 4, 12));
+    assert_eq!(output(&grid, 2, 3), Some(Decimal::from(32)));
+    assert_eq!(grid.get(1, 2).map(|p| p.values.clone()), Some(vec![Decimal::ONE, Decimal::TWO]));
+    assert!(grid.warnings().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_same_parameter_twice_rejected() {
+    let sweep = Sweep::new(&Plane, CalcInput::new(), range("x", 0, 2, 1)).by(range("x", 0, 1, 1));
+    assert!(matches!(sweep.run(), Err(Error::Validation(_))));
+}
@@ -0,0 +1,54 @@
+//! Temperature stored in °C and readable from °C or °F.
+
+use crate::{Error, Measurement, Quantity, Result, Unit, Validator};
+use rust_decimal::Decimal;
+
+/// Temperature (−5–100 °C, 23–212 °F).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Temperature(Decimal);
+
+impl Temperature {
+    pub fn celsius(value: Decimal) -> Result<Self> {
+        Validator::temp_c(value)?;
+        Ok(Self(value))
+    }
+
+    pub fn fahrenheit(value: Decimal) -> Result<Self> {
+        Validator::temp_f(value)?;
+        Ok(Self((value - Decimal::from(32)) / Decimal::new(18, 1)))
+    }
+
+    pub fn as_celsius(self) -> Decimal {
+        self.0
+    }
+
+    /// °C × 1.8 + 32.
+    pub fn as_fahrenheit(self) -> Decimal {
+        self.0 * Decimal::new(18, 1) + Decimal::from(32)
+    }
+}
+
+impl Quantity for Temperature {
+    const NAME: &'static str = "temperature";
+    const UNIT: Unit = Unit::Celsius;
+
+    fn new(value: Decimal) -> Result<Self> {
+        Self::celsius(value)
+    }
+
+    fn base(self) -> Decimal {
+        self.0
+    }
+
+    fn accepts(unit: Unit) -> bool {
+        matches!(unit, Unit::Celsius | Unit::Fahrenheit)
+    }
+
+    fn from_measurement(m: Measurement) -> Result<Self> {
+        match m.unit {
+            Unit::Celsius => Self::celsius(m.value),
+            Unit::Fahrenheit => Self::fahrenheit(m.value),
+            _ => Err(Error::Validation(format!("{} is not a {}", m, Self::NAME))),
+        }
+    }
+}
@@ -1,17 +1,52 @@
-//! Calculator trait and registry system.
+//! Calculator trait.
 
-use crate::{CalcInput, CalcResult, Error, Result};
+use crate::{CalcInput, CalcResult, Category, Error, Result};
+
+// Registry items keep their historical `traits::` paths.
+pub use crate::registry::{get_calculator, list_calculators, CalculatorEntry, CALCULATORS};
 
 /// Calculator trait for all computation modules.
 pub trait Calculator: Send + Sync {
     /// Unique identifier.
-    fn id(&self) -> &'static str;
+    fn id(&self) -> &str;
+
+    /// Former IDs that still resolve to this calculator.
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
 
     /// Display name.
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
 
     /// Description.
-    fn description(&self) -> &'static str;
+    fn description(&self) -> &str;
+
+    /// Front-end grouping.
+    fn category(&self) -> Category {
+        Category::Basic
+    }
+
+    /// Short tags shown with the name and matched by search.
+    fn tags(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Extra search terms (synonyms, abbreviations) that are not displayed.
+    fn keywords(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Position within the category; lower sorts first.
+    fn sort_order(&self) -> u32 {
+        100
+    }
+
+    /// Formula version. Bump it whenever a change gives different results for
+    /// the same input, so stored [`CalculationRecord`](crate::CalculationRecord)s
+    /// can tell which formula produced them.
+    fn version(&self) -> u32 {
+        1
+    }
 
     /// Perform calculation.
     fn calculate(&self, input: CalcInput) -> Result<CalcResult>;
@@ -24,44 +59,3 @@ pub trait Calculator: Send + Sync {
         Ok(())
     }
 }
-
-/// Calculator registry entry.
-pub struct CalculatorEntry {
-    pub id: &'static str,
-    pub factory: fn() -> Box<dyn Calculator>,
-}
-
-impl CalculatorEntry {
-    pub const fn new(id: &'static str, factory: fn() -> Box<dyn Calculator>) -> Self {
-        Self { id, factory }
-    }
-}
-
-/// Inventory of all calculators.
-#[linkme::distributed_slice]
-pub static CALCULATORS: [CalculatorEntry];
-
-/// Get calculator by ID.
-pub fn get_calculator(id: &str) -> Option<Box<dyn Calculator>> {
-    CALCULATORS
-        .iter()
-        .find(|e| e.id == id)
-        .map(|e| (e.factory)())
-}
-
-/// List all calculator IDs.
-pub fn list_calculators() -> Vec<&'static str> {
-    CALCULATORS.iter().map(|e| e.id).collect()
-}
-
-/// Macro to register a calculator.
-#[macro_export]
-macro_rules! register_calculator {
-    ($calc:ty) => {
-        #[::linkme::distributed_slice($crate::traits::CALCULATORS)]
-        static ENTRY: $crate::traits::CalculatorEntry = $crate::traits::CalculatorEntry::new(
-            <$calc>::ID,
-            || Box::new(<$calc>::default()),
-        );
-    };
-}
@@ -17,6 +17,20 @@ pub enum Unit {
     Liters,
     Milliliters,
     Abv,
+    GramsPerLiter,
+    Kilocalories,
+    Psi,
+    Meters,
+    Minutes,
+    PasteurizationUnits,
+    Proof,
+    StandardDrinks,
+    Ibu,
+    Ratio,
+    Srm,
+    Ebc,
+    Mcu,
+    Ppm,
 }
 
 impl Unit {
@@ -29,6 +43,18 @@ impl Unit {
             Self::Celsius | Self::Fahrenheit => 1,
             Self::Percent | Self::Abv => 2,
             Self::Grams | Self::Liters | Self::Milliliters => 2,
+            Self::GramsPerLiter => 1,
+            Self::Kilocalories => 0,
+            Self::Psi => 1,
+            Self::Meters => 2,
+            Self::Minutes => 1,
+            Self::PasteurizationUnits => 1,
+            Self::Proof => 1,
+            Self::StandardDrinks => 1,
+            Self::Ibu => 1,
+            Self::Ratio => 2,
+            Self::Srm | Self::Ebc | Self::Mcu => 1,
+            Self::Ppm => 0,
         }
     }
 
@@ -46,6 +72,20 @@ impl Unit {
             Self::Grams => "g",
             Self::Liters => "L",
             Self::Milliliters => "mL",
+            Self::GramsPerLiter => "g/L",
+            Self::Kilocalories => "kcal",
+            Self::Psi => "psi",
+            Self::Meters => "m",
+            Self::Minutes => "min",
+            Self::PasteurizationUnits => "PU",
+            Self::Proof => "proof",
+            Self::StandardDrinks => "std drinks",
+            Self::Ibu => "IBU",
+            Self::Ratio => "ratio",
+            Self::Srm => "SRM",
+            Self::Ebc => "EBC",
+            Self::Mcu => "MCU",
+            Self::Ppm => "ppm",
         }
     }
 }
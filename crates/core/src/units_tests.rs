@@ -15,6 +15,20 @@ mod tests {
         assert_eq!(Unit::Grams.symbol(), "g");
         assert_eq!(Unit::Liters.symbol(), "L");
         assert_eq!(Unit::Milliliters.symbol(), "mL");
+        assert_eq!(Unit::GramsPerLiter.symbol(), "g/L");
+        assert_eq!(Unit::Kilocalories.symbol(), "kcal");
+        assert_eq!(Unit::Psi.symbol(), "psi");
+        assert_eq!(Unit::Meters.symbol(), "m");
+        assert_eq!(Unit::Minutes.symbol(), "min");
+        assert_eq!(Unit::PasteurizationUnits.symbol(), "PU");
+        assert_eq!(Unit::Proof.symbol(), "proof");
+        assert_eq!(Unit::StandardDrinks.symbol(), "std drinks");
+        assert_eq!(Unit::Ibu.symbol(), "IBU");
+        assert_eq!(Unit::Ratio.symbol(), "ratio");
+        assert_eq!(Unit::Srm.symbol(), "SRM");
+        assert_eq!(Unit::Ebc.symbol(), "EBC");
+        assert_eq!(Unit::Mcu.symbol(), "MCU");
+        assert_eq!(Unit::Ppm.symbol(), "ppm");
     }
 
     #[test]
@@ -30,6 +44,20 @@ mod tests {
         assert_eq!(Unit::Grams.precision(), 2);
         assert_eq!(Unit::Liters.precision(), 2);
         assert_eq!(Unit::Milliliters.precision(), 2);
+        assert_eq!(Unit::GramsPerLiter.precision(), 1);
+        assert_eq!(Unit::Kilocalories.precision(), 0);
+        assert_eq!(Unit::Psi.precision(), 1);
+        assert_eq!(Unit::Meters.precision(), 2);
+        assert_eq!(Unit::Minutes.precision(), 1);
+        assert_eq!(Unit::PasteurizationUnits.precision(), 1);
+        assert_eq!(Unit::Proof.precision(), 1);
+        assert_eq!(Unit::StandardDrinks.precision(), 1);
+        assert_eq!(Unit::Ibu.precision(), 1);
+        assert_eq!(Unit::Ratio.precision(), 2);
+        assert_eq!(Unit::Srm.precision(), 1);
+        assert_eq!(Unit::Ebc.precision(), 1);
+        assert_eq!(Unit::Mcu.precision(), 1);
+        assert_eq!(Unit::Ppm.precision(), 0);
     }
 
     #[test]
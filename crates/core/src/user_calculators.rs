@@ -0,0 +1,97 @@
+//! Calculators registered at runtime, such as TOML formulas, alongside the compiled registry.
+
+use crate::registry::{by_order, find_calculator, get_all_calculators};
+use crate::{Calculator, Error, Result};
+use std::collections::HashSet;
+use std::ops::Deref;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+/// A compiled calculator or a user calculator, usable as `&dyn Calculator`.
+///
+/// Compiled calculators stay `&'static`, so only user formulas are
+/// reference-counted; a reload frees the replaced ones once no handle holds them.
+#[derive(Clone)]
+pub enum CalculatorHandle {
+    Compiled(&'static dyn Calculator),
+    User(Arc<dyn Calculator>),
+}
+
+impl Deref for CalculatorHandle {
+    type Target = dyn Calculator;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Compiled(calc) => *calc,
+            Self::User(calc) => calc.as_ref(),
+        }
+    }
+}
+
+static USER: RwLock<Vec<Arc<dyn Calculator>>> = RwLock::new(Vec::new());
+
+type Snapshot = Arc<[CalculatorHandle]>;
+
+/// Compiled and user calculators in display order, rebuilt when user calculators change.
+static ALL: OnceLock<RwLock<Snapshot>> = OnceLock::new();
+
+fn all() -> &'static RwLock<Snapshot> {
+    ALL.get_or_init(|| RwLock::new(merge(&[])))
+}
+
+/// Compiled calculators plus `user`, sorted for display.
+fn merge(user: &[Arc<dyn Calculator>]) -> Snapshot {
+    let compiled = get_all_calculators().iter().map(|c| CalculatorHandle::Compiled(*c));
+    let mut calculators: Vec<_> =
+        compiled.chain(user.iter().cloned().map(CalculatorHandle::User)).collect();
+    calculators.sort_by(|a, b| by_order(&**a, &**b));
+    calculators.into()
+}
+
+/// Replace every user calculator with `calculators`.
+///
+/// IDs and aliases may not clash with compiled calculators or with each other.
+/// On error the previous set stays registered. Replaced calculators are freed
+/// once no handle still holds them.
+pub fn register_user_calculators(calculators: Vec<Box<dyn Calculator>>) -> Result<()> {
+    let mut seen = HashSet::new();
+    for calc in &calculators {
+        for name in std::iter::once(calc.id()).chain(calc.aliases().iter().copied()) {
+            if let Some(existing) = find_calculator(name) {
+                return Err(Error::Config(format!(
+                    "'{}' of '{}' is already registered by '{}'",
+                    name,
+                    calc.name(),
+                    existing.name()
+                )));
+            }
+            if !seen.insert(name) {
+                return Err(Error::Config(format!("'{}' is defined more than once", name)));
+            }
+        }
+    }
+
+    let mut user = USER.write().unwrap_or_else(PoisonError::into_inner);
+    *user = calculators.into_iter().map(Arc::from).collect();
+    *all().write().unwrap_or_else(PoisonError::into_inner) = merge(&user);
+    Ok(())
+}
+
+/// Calculators currently registered at runtime, in registration order.
+pub fn user_calculators() -> Vec<Arc<dyn Calculator>> {
+    USER.read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// All compiled and user calculators, ordered by category, sort order and name.
+pub fn calculator_handles() -> Arc<[CalculatorHandle]> {
+    Arc::clone(&all().read().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Find a compiled or user calculator by ID or alias, compiled calculators first.
+pub fn find_handle(id: &str) -> Option<CalculatorHandle> {
+    if let Some(calc) = find_calculator(id) {
+        return Some(CalculatorHandle::Compiled(calc));
+    }
+    let user = USER.read().unwrap_or_else(PoisonError::into_inner);
+    let found = user.iter().find(|calc| calc.id() == id || calc.aliases().contains(&id));
+    found.cloned().map(CalculatorHandle::User)
+}
@@ -2,7 +2,6 @@
 
 use crate::{Error, Result};
 use rust_decimal::Decimal;
-use std::str::FromStr;
 
 /// Range validator with warnings.
 pub struct Validator;
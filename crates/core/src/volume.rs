@@ -0,0 +1,71 @@
+//! Volume stored in litres and readable from L or mL.
+
+use crate::{Error, Measurement, Quantity, Result, Unit};
+use rust_decimal::Decimal;
+
+/// Litres per US gallon.
+const LITERS_PER_GALLON: Decimal = Decimal::from_parts(3785411784, 0, 0, false, 9);
+
+/// Positive volume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Volume(Decimal);
+
+impl Volume {
+    pub fn liters(value: Decimal) -> Result<Self> {
+        if value <= Decimal::ZERO {
+            return Err(Error::Validation("Volume must be positive".into()));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn milliliters(value: Decimal) -> Result<Self> {
+        Self::liters(value / Decimal::from(1000))
+    }
+
+    /// US gallons.
+    pub fn gallons(value: Decimal) -> Result<Self> {
+        Self::liters(value * LITERS_PER_GALLON)
+    }
+
+    pub fn as_liters(self) -> Decimal {
+        self.0
+    }
+
+    pub fn as_milliliters(self) -> Decimal {
+        self.0 * Decimal::from(1000)
+    }
+
+    pub fn as_gallons(self) -> Decimal {
+        self.0 / LITERS_PER_GALLON
+    }
+
+    /// Hectolitres, the basis of most dosing rates.
+    pub fn as_hectoliters(self) -> Decimal {
+        self.0 / Decimal::from(100)
+    }
+}
+
+impl Quantity for Volume {
+    const NAME: &'static str = "volume";
+    const UNIT: Unit = Unit::Liters;
+
+    fn new(value: Decimal) -> Result<Self> {
+        Self::liters(value)
+    }
+
+    fn base(self) -> Decimal {
+        self.0
+    }
+
+    fn accepts(unit: Unit) -> bool {
+        matches!(unit, Unit::Liters | Unit::Milliliters)
+    }
+
+    fn from_measurement(m: Measurement) -> Result<Self> {
+        match m.unit {
+            Unit::Liters => Self::liters(m.value),
+            Unit::Milliliters => Self::milliliters(m.value),
+            _ => Err(Error::Validation(format!("{} is not a {}", m, Self::NAME))),
+        }
+    }
+}
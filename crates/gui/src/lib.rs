@@ -2,16 +2,20 @@
 mod state;
 
 use eframe::egui::{self, Color32, RichText, Rounding, Stroke, Vec2};
-use mazerion_core::{CalcInput, Measurement, Result, Unit};
+use mazerion_core::{CalcInput, Measurement, Result, TraceStep, Unit};
 use rust_decimal::Decimal;
 use std::str::FromStr;
 use mazerion_core::traits::get_calculator;
+use mazerion_config::FormulaWatcher;
+use std::time::Duration;
 
 // Import state management
 use state::{AppState, TabView, BasicCalculator, AdvancedCalculator, BrewingCalculator, FinishingCalculator, colors};
 
 pub struct MazerionApp {
     state: AppState,
+    formulas: Option<FormulaWatcher>,
+    formula_status: Option<String>,
 
     // Input fields (organized by calculator type)
     // Basic
@@ -40,6 +44,7 @@ pub struct MazerionApp {
     target_co2: String,
     carb_method: String,
     sugar_type: String,
+    grains: String,
 
     // Finishing
     sweet_vol: String,
@@ -58,12 +63,16 @@ pub struct MazerionApp {
     result: Option<String>,
     warnings: Vec<String>,
     metadata: Vec<(String, String)>,
+    trace: Vec<TraceStep>,
+    explanation: String,
 }
 
 impl Default for MazerionApp {
     fn default() -> Self {
         Self {
             state: AppState::default(),
+            formulas: None,
+            formula_status: None,
 
             og: "1.090".to_string(),
             fg: "1.010".to_string(),
@@ -88,6 +97,7 @@ impl Default for MazerionApp {
             target_co2: "2.5".to_string(),
             carb_method: "priming".to_string(),
             sugar_type: "table_sugar".to_string(),
+            grains: "4.5:2, 0.3:60".to_string(),
 
             sweet_vol: "19.0".to_string(),
             current_sg: "0.995".to_string(),
@@ -104,12 +114,17 @@ impl Default for MazerionApp {
             result: None,
             warnings: Vec::new(),
             metadata: Vec::new(),
+            trace: Vec::new(),
+            explanation: String::new(),
         }
     }
 }
 
 impl eframe::App for MazerionApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Pick up edits to the formula file
+        self.reload_formulas(ctx);
+
         // Custom style
         self.apply_custom_style(ctx);
 
@@ -153,6 +168,19 @@ impl MazerionApp {
         ctx.set_style(style);
     }
 
+    // Re-register user formulas when their file changes, checking again each second
+    fn reload_formulas(&mut self, ctx: &egui::Context) {
+        let Some(watcher) = self.formulas.as_mut() else {
+            return;
+        };
+        match watcher.poll() {
+            Ok(Some(count)) => self.formula_status = Some(format!("Loaded {} user formulas", count)),
+            Ok(None) => {}
+            Err(e) => self.formula_status = Some(format!("Formula error: {}", e)),
+        }
+        ctx.request_repaint_after(Duration::from_secs(1));
+    }
+
     fn render_header(&self, ui: &mut egui::Ui) {
         ui.vertical_centered(|ui| {
             ui.heading(RichText::new("🍯 Mazerion")
@@ -162,6 +190,9 @@ impl MazerionApp {
             ui.label(RichText::new("Professional Beverage Calculator Suite")
                 .size(16.0)
                 .color(colors::GOLDENROD));
+            if let Some(status) = &self.formula_status {
+                ui.label(RichText::new(status).size(12.0).color(colors::SADDLE_BROWN));
+            }
         });
     }
 
@@ -207,6 +238,8 @@ impl MazerionApp {
         self.result = None;
         self.warnings.clear();
         self.metadata.clear();
+        self.trace.clear();
+        self.explanation.clear();
     }
 
     fn calculate_button(&mut self, ui: &mut egui::Ui, text: &str) -> bool {
@@ -252,6 +285,22 @@ impl MazerionApp {
                     }
                 }
 
+                if !self.trace.is_empty() {
+                    ui.add_space(8.0);
+                    ui.collapsing("🧮 Show working", |ui| {
+                        for (n, step) in self.trace.iter().enumerate() {
+                            ui.label(RichText::new(format!("{}. {}", n + 1, step.label)).strong());
+                            ui.monospace(format!("   {}", step.expression));
+                            ui.monospace(format!(" = {}", step.substituted));
+                            ui.monospace(format!(" = {}", step.value.normalize()));
+                        }
+                        ui.add_space(4.0);
+                        if ui.button("📋 Copy working").on_hover_text("Copy the steps and result as text").clicked() {
+                            ui.ctx().copy_text(self.explanation.clone());
+                        }
+                    });
+                }
+
                 if !self.metadata.is_empty() {
                     ui.add_space(8.0);
                     ui.collapsing("ℹ️ Additional Information", |ui| {
@@ -274,7 +323,8 @@ impl MazerionApp {
 
 // Tab implementations will continue in next file...
 
-pub fn run() -> eframe::Result {
+/// Launch the GUI, reloading user formulas from `formulas` while it runs.
+pub fn run(formulas: Option<FormulaWatcher>) -> eframe::Result {
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_inner_size([900.0, 700.0])
@@ -285,6 +335,6 @@ pub fn run() -> eframe::Result {
     eframe::run_native(
         "Mazerion - Professional Beverage Calculator",
         options,
-        Box::new(|_cc| Ok(Box::new(MazerionApp::default()))),
+        Box::new(move |_cc| Ok(Box::new(MazerionApp { formulas, ..MazerionApp::default() }))),
     )
 }
\ No newline at end of file
@@ -55,6 +55,7 @@ pub enum AdvancedCalculator {
 pub enum BrewingCalculator {
     Nutrition,
     Carbonation,
+    Colour,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
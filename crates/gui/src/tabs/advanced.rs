@@ -113,13 +113,17 @@ impl MazerionApp {
         match calc.calculate(input) {
             Ok(res) => {
                 self.result = Some(format!("Blended ABV: {:.2}%", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
@@ -156,13 +160,17 @@ impl MazerionApp {
         match calc.calculate(input) {
             Ok(res) => {
                 self.result = Some(format!("Corrected FG: {:.4}", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
@@ -215,13 +223,17 @@ impl MazerionApp {
         match calc.calculate(input) {
             Ok(res) => {
                 self.result = Some(format!("Corrected SG: {:.4}", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
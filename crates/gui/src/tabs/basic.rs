@@ -107,13 +107,17 @@ impl MazerionApp {
         match calc.calculate(input) {
             Ok(res) => {
                 self.result = Some(format!("ABV: {:.2}%", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
@@ -148,13 +152,17 @@ impl MazerionApp {
         match calc.calculate(input) {
             Ok(res) => {
                 self.result = Some(format!("Specific Gravity: {:.4}", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
@@ -176,13 +184,17 @@ impl MazerionApp {
         match calc.calculate(input) {
             Ok(res) => {
                 self.result = Some(format!("Water to Add: {:.2} L", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
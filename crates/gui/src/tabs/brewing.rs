@@ -1,5 +1,6 @@
 // Brewing tab implementation - Nutrition (TOSNA), Carbonation
 
+use super::helpers::colour_chip;
 use crate::{MazerionApp, state::{BrewingCalculator, colors}};
 use eframe::egui::{self, RichText, Rounding};
 use mazerion_core::CalcInput;
@@ -13,6 +14,7 @@ impl MazerionApp {
                 .show_ui(ui, |ui| {
                     ui.selectable_value(&mut self.state.brewing_calc, BrewingCalculator::Nutrition, "TOSNA Nutrition Calculator");
                     ui.selectable_value(&mut self.state.brewing_calc, BrewingCalculator::Carbonation, "Carbonation Calculator");
+                    ui.selectable_value(&mut self.state.brewing_calc, BrewingCalculator::Colour, "Colour Calculator");
                 });
         });
 
@@ -27,6 +29,7 @@ impl MazerionApp {
                 match self.state.brewing_calc {
                     BrewingCalculator::Nutrition => self.render_nutrition_calculator(ui),
                     BrewingCalculator::Carbonation => self.render_carbonation_calculator(ui),
+                    BrewingCalculator::Colour => self.render_colour_calculator(ui),
                 }
             });
     }
@@ -35,6 +38,7 @@ impl MazerionApp {
         match self.state.brewing_calc {
             BrewingCalculator::Nutrition => "TOSNA Nutrition Calculator",
             BrewingCalculator::Carbonation => "Carbonation Calculator",
+            BrewingCalculator::Colour => "Colour Calculator",
         }
     }
 
@@ -104,6 +108,29 @@ impl MazerionApp {
         }
     }
 
+    fn render_colour_calculator(&mut self, ui: &mut egui::Ui) {
+        ui.heading(RichText::new("🎨 Colour Calculator").color(colors::SADDLE_BROWN));
+        ui.label("Estimate SRM/EBC colour from a grain bill");
+        ui.add_space(10.0);
+
+        self.input_field(ui, "Volume (L):", &mut self.volume, "Post-boil volume");
+        self.input_field(ui, "Grains (kg:°L):", &mut self.grains, "e.g. 4.5:2, 0.3:60");
+
+        ui.add_space(10.0);
+
+        if self.calculate_button(ui, "Calculate Colour") {
+            self.calc_colour();
+        }
+
+        if let Some((_, hex)) = self.metadata.iter().find(|(key, _)| key == "srgb") {
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                colour_chip(ui, hex);
+                ui.label(hex.as_str());
+            });
+        }
+    }
+
     // Calculation methods
     fn calc_nutrition(&mut self) {
         let calc = match mazerion_core::traits::get_calculator("nutrition") {
@@ -122,13 +149,17 @@ impl MazerionApp {
         match calc.calculate(input) {
             Ok(res) => {
                 self.result = Some(format!("Total Fermaid-O: {:.2} g", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
@@ -156,14 +187,49 @@ impl MazerionApp {
                 } else {
                     self.result = Some(format!("Target PSI: {:.1}", res.output.value));
                 }
+                self.explanation = res.explain();
+                self.warnings = res.warnings;
+                self.metadata = res.metadata;
+                self.trace = res.trace;
+            }
+            Err(e) => {
+                self.result = Some(format!("Error: {}", e));
+                self.warnings.clear();
+                self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
+            }
+        }
+    }
+
+    fn calc_colour(&mut self) {
+        let calc = match mazerion_core::traits::get_calculator("colour") {
+            Some(c) => c,
+            None => {
+                self.result = Some("Error: Colour calculator not found".to_string());
+                return;
+            }
+        };
+
+        let input = CalcInput::new()
+            .add_param("volume", &self.volume)
+            .add_param("grains", &self.grains);
+
+        match calc.calculate(input) {
+            Ok(res) => {
+                self.result = Some(format!("Colour: {:.1} SRM", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
-}
\ No newline at end of file
+}
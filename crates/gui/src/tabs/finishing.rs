@@ -48,6 +48,7 @@ impl MazerionApp {
         ui.label("Calculate sweetener additions to reach target sweetness");
 
         ui.label(RichText::new("⚠️ MUST stabilize before backsweetening!").color(colors::DARK_ORANGE).strong());
+        ui.label("Use the Potassium Sorbate calculator (volume, ABV, pH) for the sorbate and matching K-meta doses.");
         ui.add_space(10.0);
 
         self.input_field(ui, "Volume (L):", &mut self.sweet_vol, "Total volume to sweeten");
@@ -162,13 +163,17 @@ impl MazerionApp {
                                            res.output.value,
                                            res.output.value / Decimal::from(1000)
                 ));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
@@ -206,13 +211,17 @@ impl MazerionApp {
         match calc.calculate(input) {
             Ok(res) => {
                 self.result = Some(format!("K-meta: {:.2} g", res.output.value));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
@@ -260,13 +269,17 @@ impl MazerionApp {
                                            },
                                            res.output.value
                 ));
+                self.explanation = res.explain();
                 self.warnings = res.warnings;
                 self.metadata = res.metadata;
+                self.trace = res.trace;
             }
             Err(e) => {
                 self.result = Some(format!("Error: {}", e));
                 self.warnings.clear();
                 self.metadata.clear();
+                self.trace.clear();
+                self.explanation.clear();
             }
         }
     }
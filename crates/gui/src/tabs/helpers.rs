@@ -1,3 +1,4 @@
+use eframe::egui;
 use mazerion_core::{find_calculator, CalcInput};
 use rust_decimal::Decimal;
 
@@ -26,7 +27,7 @@ fn format_result(result: mazerion_core::CalcResult) -> String {
     let mut output = format!("{} {}", result.primary.value, result.primary.unit);
 
     for secondary in result.secondary {
-        output.push_str(&format!("\n{} {}", secondary.value, secondary.unit));
+        output.push_str(&format!("\n{}: {} {}", secondary.label, secondary.value, secondary.unit));
     }
 
     for warning in result.warnings {
@@ -39,3 +40,17 @@ fn format_result(result: mazerion_core::CalcResult) -> String {
 
     output
 }
+
+/// Paint a small colour chip for an `#RRGGBB` swatch such as the colour calculator's `srgb`.
+pub fn colour_chip(ui: &mut egui::Ui, hex: &str) {
+    if let Some(colour) = parse_srgb(hex) {
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(24.0, 24.0), egui::Sense::hover());
+        ui.painter().rect_filled(rect, 4.0, colour);
+    }
+}
+
+fn parse_srgb(hex: &str) -> Option<egui::Color32> {
+    let hex = hex.strip_prefix('#').filter(|h| h.len() == 6)?;
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some(egui::Color32::from_rgb(channel(0)?, channel(2)?, channel(4)?))
+}
@@ -12,18 +12,21 @@ use ratatui::{
     Terminal,
 };
 use std::io;
+use std::time::Duration;
 use ratatui::backend::Backend;
+use mazerion_config::FormulaWatcher;
 // Force calculators to register
 use mazerion_core as _;
 
-pub fn run() -> io::Result<()> {
+/// Launch the TUI, reloading user formulas from `formulas` while it runs.
+pub fn run(formulas: Option<FormulaWatcher>) -> io::Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = run_app(&mut terminal);
+    let result = run_app(&mut terminal, formulas);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -32,18 +35,32 @@ pub fn run() -> io::Result<()> {
     result
 }
 
-fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>) -> io::Result<()> where std::io::Error: From<<B as Backend>::Error> {
-    let calculators = mazerion_core::get_all_calculators();
+fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, mut formulas: Option<FormulaWatcher>) -> io::Result<()> where std::io::Error: From<<B as Backend>::Error> {
+    let mut calculators = mazerion_core::calculator_handles();
     let mut selected: usize = 0;
+    let mut status = String::new();
 
     loop {
+        // Re-register user formulas when their file changes
+        if let Some(watcher) = formulas.as_mut() {
+            match watcher.poll() {
+                Ok(Some(_)) => {
+                    calculators = mazerion_core::calculator_handles();
+                    selected = selected.min(calculators.len().saturating_sub(1));
+                    status.clear();
+                }
+                Ok(None) => {}
+                Err(e) => status = format!(" - Formula error: {}", e),
+            }
+        }
+
         terminal.draw(|f| {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([Constraint::Length(3), Constraint::Min(0)])
                 .split(f.area());
 
-            let title = Paragraph::new("🍯 Mazerion TUI - Press 'q' to quit")
+            let title = Paragraph::new(format!("🍯 Mazerion TUI - Press 'q' to quit{}", status))
                 .block(Block::default().borders(Borders::ALL))
                 .style(Style::default().fg(Color::Cyan));
             f.render_widget(title, chunks[0]);
@@ -71,6 +88,10 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>) -> io::Resu
             f.render_widget(list, chunks[1]);
         })?;
 
+        // Wake up periodically so formula edits show without a key press
+        if !event::poll(Duration::from_millis(500))? {
+            continue;
+        }
         if let Event::Key(key) = event::read()? {
             match key.code {
                 KeyCode::Char('q') => return Ok(()),